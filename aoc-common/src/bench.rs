@@ -0,0 +1,141 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Error, Solution, Source,
+    json::Object,
+    runner::{self, Options},
+    warnings,
+};
+
+/// Summary of repeated timings of one step.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> Object {
+        Object::new()
+            .field("min_ns", &self.min.as_nanos())
+            .field("median_ns", &self.median.as_nanos())
+            .field("max_ns", &self.max.as_nanos())
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// Parse and solve timings of one day.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u8,
+    pub parse: Stats,
+    /// Timings of every part that was run.
+    pub parts: Vec<(u8, Stats)>,
+}
+
+impl fmt::Display for Bench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parse   {}", self.parse)?;
+        for (part, stats) in &self.parts {
+            write!(f, "\npart {}  {}", part, stats)?;
+        }
+        Ok(())
+    }
+}
+
+/// Times parsing and every requested part of `S` over `iterations` runs.
+///
+/// Parts a day does not implement are skipped unless asked for explicitly.
+pub fn bench<S: Solution>(options: &Options, iterations: usize) -> Result<Bench, Error> {
+    assert!(iterations > 0, "benchmarks need at least one iteration");
+    options.flags.check_known(S::FLAGS)?;
+    let text = Source::from_arg(options.input.as_deref()).read()?;
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut input = None;
+    for _ in 0..iterations {
+        let mut flags = options.flags.clone();
+        let start = Instant::now();
+        let parsed = S::parse_with(black_box(&text), &mut flags);
+        samples.push(start.elapsed());
+        input = Some(parsed.map_err(|err| err.with_snippet(&text))?);
+        flags.finish()?;
+    }
+    warnings::take();
+    let input = input.expect("at least one iteration ran");
+    let parse = Stats::from_samples(samples);
+
+    let mut parts = Vec::new();
+    for part in options.parts() {
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let answer = runner::solve::<S>(black_box(&input), part);
+            samples.push(start.elapsed());
+            match answer {
+                Ok(answer) => {
+                    black_box(answer);
+                    warnings::take();
+                }
+                Err(Error::Missing { .. }) if options.part.is_none() => break,
+                Err(err) => return Err(err),
+            }
+        }
+        if samples.len() == iterations {
+            parts.push((part, Stats::from_samples(samples)));
+        }
+    }
+
+    Ok(Bench {
+        day: S::DAY,
+        parse,
+        parts,
+    })
+}
+
+/// Renders benchmark results as JSON, with all durations in nanoseconds.
+pub fn to_json(results: &[Bench], iterations: usize) -> String {
+    let mut out = format!("{{\n  \"iterations\": {},\n  \"days\": [", iterations);
+    for (i, bench) in results.iter().enumerate() {
+        out.push_str(if i == 0 { "\n    " } else { ",\n    " });
+        let mut day = Object::new()
+            .field("day", &bench.day)
+            .field("parse", &bench.parse.to_json());
+        for (part, stats) in &bench.parts {
+            day = day.field(&format!("part{}", part), &stats.to_json());
+        }
+        out.push_str(&day.finish());
+    }
+    out.push_str("\n  ]\n}\n");
+    out
+}
@@ -0,0 +1,159 @@
+use std::{fmt, io};
+
+use crate::Line;
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse(ParseError),
+    Usage(String),
+    /// The input parsed but has no answer, e.g. an unsolvable machine.
+    NoSolution(String),
+    /// The day does not implement this part.
+    Missing {
+        day: u8,
+        part: u8,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "cannot read {}: {}", path, source),
+            Error::Parse(err) => err.fmt(f),
+            Error::Usage(message) => write!(f, "usage: {}", message),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::Missing { day, part } => {
+                write!(f, "day {} part {} is not implemented", day, part)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    /// Attaches the offending line of `input` to parse errors.
+    pub fn with_snippet(self, input: &str) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(err.with_snippet(input)),
+            err => err,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+/// A parse failure pinned to the place in the input that caused it.
+///
+/// `line` and `column` are 1-based and count from the start of the text that
+/// was parsed; `kind` is the day specific error enum saying what went wrong.
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub kind: Box<dyn std::error::Error + Send + Sync>,
+    /// The offending input line, once known, for display.
+    snippet: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        token: impl Into<String>,
+        kind: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            token: token.into(),
+            kind: kind.into(),
+            snippet: None,
+        }
+    }
+
+    /// Shorthand for an error on the first line of a single line of text.
+    pub fn at(
+        column: usize,
+        token: impl Into<String>,
+        kind: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        ParseError::new(1, column, token, kind)
+    }
+
+    /// The day specific error, if it is a `K`.
+    pub fn kind<K: std::error::Error + 'static>(&self) -> Option<&K> {
+        self.kind.downcast_ref()
+    }
+
+    /// Moves an error reported against the text of `line` to where that line
+    /// sits in the whole input.
+    pub fn relative_to(mut self, line: &Line) -> Self {
+        if self.line == 1 {
+            self.column += line.column - 1;
+        }
+        self.line += line.number - 1;
+        self
+    }
+
+    /// Attaches the offending line of `input` so it is shown with the error.
+    pub fn with_snippet(mut self, input: &str) -> Self {
+        self.snippet = input.lines().nth(self.line - 1).map(str::to_owned);
+        self
+    }
+
+    /// Like `with_snippet`, given just the offending line itself.
+    pub(crate) fn with_snippet_line(mut self, line: &str) -> Self {
+        self.snippet = Some(line.to_owned());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+
+        match &self.snippet {
+            Some(snippet) => {
+                let gutter = " ".repeat(self.line.to_string().len());
+                let carets = "^".repeat(self.token.chars().count().max(1));
+                write!(f, "\n{} |\n{} | {}", gutter, self.line, snippet)?;
+                write!(
+                    f,
+                    "\n{} | {}{}",
+                    gutter,
+                    " ".repeat(self.column - 1),
+                    carets
+                )
+            }
+            None => write!(f, " (at `{}`)", self.token),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.kind.as_ref())
+    }
+}
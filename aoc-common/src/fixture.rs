@@ -0,0 +1,95 @@
+use std::{fmt, fs, path::Path};
+
+use crate::{Error, ParseError, Source, lines, runner::Options};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FixtureError {
+    UnknownLine,
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureError::UnknownLine => {
+                write!(
+                    f,
+                    "expected `part1: ANSWER`, `part2: ANSWER` or `flags: OPTIONS`"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {}
+
+/// A puzzle input together with the answers it is known to produce.
+///
+/// Stored as `NAME.answers` next to the input `NAME.txt`, holding a
+/// `partN: ANSWER` line per recorded part and optionally a `flags:` line with
+/// the day specific options the answers were produced with.
+pub struct Fixture {
+    pub name: String,
+    pub options: Options,
+    /// Recorded parts with their answers; unlisted parts are not checked.
+    pub answers: Vec<(u8, String)>,
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = Source::File(path.to_owned()).read()?;
+        let mut flags = Vec::new();
+        let mut answers = Vec::new();
+
+        for line in lines(&text) {
+            let entry = line.text.split_once(':').map(|(k, v)| (k.trim(), v.trim()));
+            match entry {
+                Some(("part1", answer)) => answers.push((1, answer.to_owned())),
+                Some(("part2", answer)) => answers.push((2, answer.to_owned())),
+                Some(("flags", options)) => {
+                    flags.extend(options.split_whitespace().map(str::to_owned))
+                }
+                _ => {
+                    let err = ParseError::new(
+                        line.number,
+                        line.column,
+                        line.text,
+                        FixtureError::UnknownLine,
+                    );
+                    return Err(Error::from(err).with_snippet(&text));
+                }
+            }
+        }
+
+        let mut options = Options::parse(flags)?;
+        options.input = Some(path.with_extension("txt").display().to_string());
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(Fixture {
+            name,
+            options,
+            answers,
+        })
+    }
+
+    /// Loads every `*.answers` fixture in `dir`, sorted by name.
+    pub fn load_dir(dir: &Path) -> Result<Vec<Self>, Error> {
+        let io_error = |source| Error::Io {
+            path: dir.display().to_string(),
+            source,
+        };
+
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if path.extension().is_some_and(|ext| ext == "answers") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        paths.iter().map(|path| Fixture::load(path)).collect()
+    }
+}
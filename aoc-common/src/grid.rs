@@ -0,0 +1,318 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, lines};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    RaggedRow { expected: usize, found: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::RaggedRow { expected, found } => {
+                write!(f, "row is {} cells wide, expected {}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A position in a grid as `(x, y)`, with `x` counting columns from the left
+/// and `y` rows from the top.
+pub type Pos = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells stored flat, row after row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map with a row per non-blank line, turning every
+    /// character into a cell with `cell`.
+    ///
+    /// Errors from `cell` are reported at the character that caused them.
+    pub fn parse<K>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, K>,
+    ) -> Result<Self, ParseError>
+    where
+        K: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines(input) {
+            let start = cells.len();
+            for (i, c) in line.text.chars().enumerate() {
+                let value = cell(c)
+                    .map_err(|kind| ParseError::new(line.number, line.column + i, c, kind))?;
+                cells.push(value);
+            }
+
+            let found = cells.len() - start;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(ParseError::new(
+                    line.number,
+                    line.column,
+                    line.text,
+                    GridError::RaggedRow { expected, found },
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.1 * self.width + pos.0])
+    }
+
+    /// The position `(dx, dy)` away from `pos`, if it is inside the grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to 4 positions sharing an edge with `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    /// The up to 8 positions sharing an edge or a corner with `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&offset| self.step(pos, offset))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Draws the grid with a character per cell and a line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&cell));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of bounds for {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of bounds for {}x{} grid",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).ok_or("not a digit"))
+    }
+
+    #[test]
+    fn cells_outside_the_grid_are_none() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((0, 0)), Some(&1));
+        assert_eq!(grid.get((2, 1)), Some(&6));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((0, 0), (0, -1)), None);
+        assert_eq!(grid.step((2, 1), (1, 0)), None);
+        assert_eq!(grid.step((2, 1), (0, 1)), None);
+        assert_eq!(grid.step((1, 0), (1, 1)), Some((2, 1)));
+    }
+
+    #[test]
+    fn neighbours_stop_at_the_edges() {
+        let grid = Grid::new(3, 3, ());
+        let counts = |pos| (grid.neighbors4(pos).count(), grid.neighbors8(pos).count());
+        assert_eq!(counts((0, 0)), (2, 3));
+        assert_eq!(counts((2, 2)), (2, 3));
+        assert_eq!(counts((1, 0)), (3, 5));
+        assert_eq!(counts((0, 1)), (3, 5));
+        assert_eq!(counts((1, 1)), (4, 8));
+
+        let single = Grid::new(1, 1, ());
+        assert_eq!(single.neighbors4((0, 0)).count(), 0);
+        assert_eq!(single.neighbors8((0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn rows_and_columns_slice_the_cells() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn grids_may_have_no_columns() {
+        let grid = Grid::new(0, 2, 'x');
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.rows().count(), 2);
+        assert!(grid.rows().all(<[char]>::is_empty));
+        assert_eq!(grid.positions().count(), 0);
+        assert_eq!(grid.render(|&c| c), "\n\n");
+
+        let empty = digits("").unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn ragged_rows_are_reported_where_they_are() {
+        let err = digits("123\n  45\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "45"));
+        assert_eq!(
+            err.kind.downcast_ref::<GridError>(),
+            Some(&GridError::RaggedRow {
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let err = digits("12\n3x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn grids_draw_a_line_per_row() {
+        let mut grid = digits("12\n34").unwrap();
+        grid[(1, 0)] = 7;
+        assert_eq!(grid.to_string(), "17\n34\n");
+        assert_eq!(
+            grid.render(|&n| if n % 2 == 1 { '#' } else { '.' }),
+            "##\n#.\n"
+        );
+    }
+}
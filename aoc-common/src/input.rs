@@ -0,0 +1,255 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, IsTerminal, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{Error, ParseError};
+
+/// Where a puzzle input comes from.
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    /// Input compiled into the binary, such as a puzzle example.
+    Embedded(&'static str),
+}
+
+impl Source {
+    /// Picks the source from a command line argument: a path, `-` for stdin,
+    /// or when nothing is given stdin if it is piped and `input.txt` if not.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+            None if Source::stdin_is_piped() => Source::Stdin,
+            None => Source::File("input.txt".into()),
+        }
+    }
+
+    /// Whether stdin is a pipe or file someone redirected input from, rather
+    /// than a terminal or a device such as `/dev/null`.
+    pub fn stdin_is_piped() -> bool {
+        !io::stdin().is_terminal() && stdin_is_pipe_or_file()
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "<stdin>".to_owned(),
+            Source::Embedded(_) => "<embedded>".to_owned(),
+        }
+    }
+
+    fn io_error(&self, source: io::Error) -> Error {
+        Error::Io {
+            path: self.name(),
+            source,
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            Source::File(path) => {
+                std::fs::read_to_string(path).map_err(|source| self.io_error(source))
+            }
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| self.io_error(source))?;
+                Ok(input)
+            }
+            Source::Embedded(input) => Ok((*input).to_owned()),
+        }
+    }
+
+    /// Opens the source for reading a line at a time.
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, Error> {
+        Ok(match self {
+            Source::File(path) => Box::new(BufReader::new(
+                File::open(path).map_err(|source| self.io_error(source))?,
+            )),
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::Embedded(input) => Box::new(input.as_bytes()),
+        })
+    }
+
+    /// Calls `f` with every non-blank line of the source in turn, without
+    /// holding more than one line in memory.
+    pub fn for_each_line(
+        &self,
+        mut f: impl FnMut(Line<'_>) -> Result<(), ParseError>,
+    ) -> Result<(), Error> {
+        let mut reader = self.reader()?;
+        let mut buffer = String::new();
+        let mut number = 0;
+
+        loop {
+            buffer.clear();
+            let read = reader
+                .read_line(&mut buffer)
+                .map_err(|source| self.io_error(source))?;
+            if read == 0 {
+                return Ok(());
+            }
+            number += 1;
+
+            let raw = buffer.trim_end_matches(['\n', '\r']);
+            if let Some(line) = Line::new(number, raw) {
+                f(line).map_err(|err| err.with_snippet_line(raw))?;
+            }
+        }
+    }
+
+    /// Like `for_each_line`, but splits lines on `separator` too and calls `f`
+    /// with every non-blank field, so that one long line is never held whole.
+    /// Fields keep their line and column in the input.
+    pub fn for_each_field(
+        &self,
+        separator: u8,
+        mut f: impl FnMut(Line<'_>) -> Result<(), ParseError>,
+    ) -> Result<(), Error> {
+        let mut reader = self.reader()?;
+        let mut buffer = Vec::new();
+        let (mut number, mut column) = (1, 1);
+
+        loop {
+            buffer.clear();
+            let read = read_field(&mut reader, separator, &mut buffer)
+                .map_err(|source| self.io_error(source))?;
+            if read == 0 {
+                return Ok(());
+            }
+
+            let ends_line = buffer.last() == Some(&b'\n');
+            let raw = std::str::from_utf8(&buffer)
+                .map_err(|err| self.io_error(io::Error::new(io::ErrorKind::InvalidData, err)))?
+                .trim_end_matches(['\n', '\r', char::from(separator)]);
+            if let Some(mut field) = Line::new(number, raw) {
+                field.column += column - 1;
+                f(field)?;
+            }
+            if ends_line {
+                number += 1;
+                column = 1;
+            } else {
+                column += read;
+            }
+        }
+    }
+}
+
+/// Appends to `buffer` everything up to and including the next `separator` or
+/// newline, returning how many bytes that was; 0 at the end of the input.
+fn read_field(reader: &mut dyn BufRead, separator: u8, buffer: &mut Vec<u8>) -> io::Result<usize> {
+    let mut read = 0;
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        if available.is_empty() {
+            return Ok(read);
+        }
+        let (taken, done) = match available
+            .iter()
+            .position(|&byte| byte == separator || byte == b'\n')
+        {
+            Some(end) => (end + 1, true),
+            None => (available.len(), false),
+        };
+        buffer.extend_from_slice(&available[..taken]);
+        reader.consume(taken);
+        read += taken;
+        if done {
+            return Ok(read);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn stdin_is_pipe_or_file() -> bool {
+    use std::os::unix::fs::FileTypeExt;
+
+    std::fs::metadata("/dev/stdin").is_ok_and(|meta| {
+        let kind = meta.file_type();
+        kind.is_fifo() || kind.is_file() || kind.is_socket()
+    })
+}
+
+#[cfg(not(unix))]
+fn stdin_is_pipe_or_file() -> bool {
+    true
+}
+
+/// A non-blank input line with surrounding whitespace removed.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    /// 1-based column of the first character of `text`.
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The line numbered `number` with text `raw`, unless it is blank.
+    fn new(number: usize, raw: &'a str) -> Option<Self> {
+        let text = raw.trim();
+        if text.is_empty() {
+            return None;
+        }
+        Some(Line {
+            number,
+            column: raw.len() - raw.trim_start().len() + 1,
+            text,
+        })
+    }
+
+    /// Splits the line on `separator` into trimmed, non-empty fields that keep
+    /// their position in the input.
+    pub fn split(self, separator: char) -> impl Iterator<Item = Line<'a>> {
+        let mut column = self.column;
+        self.text.split(separator).filter_map(move |raw| {
+            let start = column;
+            column += raw.len() + separator.len_utf8();
+
+            let text = raw.trim();
+            if text.is_empty() {
+                return None;
+            }
+            Some(Line {
+                number: self.number,
+                column: start + raw.len() - raw.trim_start().len(),
+                text,
+            })
+        })
+    }
+
+    /// Parses the line as a `T`, placing any error at this line's position.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|err: ParseError| err.relative_to(self))
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, raw)| Line::new(i + 1, raw))
+}
+
+/// Parses every non-blank line as a `T`, reporting the first failure with its
+/// position in the whole input.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, Error>
+where
+    T: FromStr<Err = ParseError>,
+{
+    lines(input)
+        .map(|line| line.parse().map_err(Error::from))
+        .collect()
+}
@@ -0,0 +1,134 @@
+//! Just enough JSON writing for the runner's records and day specific
+//! details.
+
+use std::fmt::Write;
+
+/// A value that can be written as JSON.
+pub trait Value {
+    fn write_json(&self, out: &mut String);
+}
+
+impl Value for str {
+    fn write_json(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => {
+                    let _ = write!(out, "\\u{:04x}", c as u32);
+                }
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+}
+
+impl Value for String {
+    fn write_json(&self, out: &mut String) {
+        self.as_str().write_json(out);
+    }
+}
+
+macro_rules! number_value {
+    ($($t:ty),*) => {
+        $(impl Value for $t {
+            fn write_json(&self, out: &mut String) {
+                let _ = write!(out, "{}", self);
+            }
+        })*
+    };
+}
+
+number_value!(u8, u32, u64, usize, u128, i32, i64, f64);
+
+impl<T: Value> Value for Option<T> {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Some(value) => value.write_json(out),
+            None => out.push_str("null"),
+        }
+    }
+}
+
+impl<T: Value> Value for [T] {
+    fn write_json(&self, out: &mut String) {
+        out.push('[');
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            value.write_json(out);
+        }
+        out.push(']');
+    }
+}
+
+impl<T: Value> Value for Vec<T> {
+    fn write_json(&self, out: &mut String) {
+        self.as_slice().write_json(out);
+    }
+}
+
+/// A JSON object written on a single line, field by field.
+#[derive(Debug, Clone, Default)]
+pub struct Object(String);
+
+impl Object {
+    pub fn new() -> Self {
+        Object::default()
+    }
+
+    pub fn field(mut self, key: &str, value: &(impl Value + ?Sized)) -> Self {
+        self.0.push_str(if self.0.is_empty() { "{" } else { ", " });
+        key.write_json(&mut self.0);
+        self.0.push_str(": ");
+        value.write_json(&mut self.0);
+        self
+    }
+
+    pub fn finish(mut self) -> String {
+        if self.0.is_empty() {
+            self.0.push('{');
+        }
+        self.0.push('}');
+        self.0
+    }
+}
+
+impl Value for Object {
+    fn write_json(&self, out: &mut String) {
+        out.push_str(&self.clone().finish());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_escaped() {
+        let mut out = String::new();
+        "a\"b\\c\nd\re\tf\u{1}".write_json(&mut out);
+        assert_eq!(out, r#""a\"b\\c\nd\re\tf\u0001""#);
+    }
+
+    #[test]
+    fn objects_keep_their_field_order() {
+        let inner = Object::new().field("none", &None::<u8>);
+        let record = Object::new()
+            .field("z", &1_u8)
+            .field("a", "text")
+            .field("list", &vec![1_usize, 2])
+            .field("inner", &inner);
+        assert_eq!(
+            record.finish(),
+            r#"{"z": 1, "a": "text", "list": [1, 2], "inner": {"none": null}}"#
+        );
+        assert_eq!(Object::new().finish(), "{}");
+    }
+}
@@ -0,0 +1,17 @@
+pub mod bench;
+mod error;
+pub mod fixture;
+pub mod grid;
+mod input;
+pub mod json;
+mod rng;
+pub mod runner;
+mod solution;
+mod warnings;
+
+pub use error::{Error, ParseError};
+pub use grid::Grid;
+pub use input::{Line, Source, lines, parse_lines};
+pub use rng::Rng;
+pub use solution::{Answer, Generate, Solution, Streaming};
+pub use warnings::warn;
@@ -0,0 +1,533 @@
+use std::{
+    fs,
+    io::{self, BufWriter, Write},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Answer, Error, Generate, Line, Rng, Solution, Source, Streaming,
+    bench::{self, Bench},
+    fixture::Fixture,
+    json::Object,
+    warnings,
+};
+
+/// Writes a generated input of about the given size, returning the answers
+/// known by construction.
+pub type Generator = fn(&mut Rng, usize, &mut dyn Write) -> io::Result<Vec<(u8, Answer)>>;
+
+/// The entry points of one day, with its `Solution` type erased.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub run: fn(&Options) -> Result<(), Error>,
+    pub check: fn(&Fixture) -> Result<bool, Error>,
+    pub bench: fn(&Options, usize) -> Result<Bench, Error>,
+    pub generate: Option<Generator>,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            run: run::<S>,
+            check: check::<S>,
+            bench: bench::bench::<S>,
+            generate: None,
+        }
+    }
+
+    /// A day that reads its input line by line with `--stream`.
+    pub fn streaming<S: Streaming>() -> Self {
+        Day {
+            run: run_streaming::<S>,
+            check: check_streaming::<S>,
+            ..Day::of::<S>()
+        }
+    }
+
+    /// Adds the input generator of `S`.
+    pub fn generating<S: Generate>(self) -> Self {
+        Day {
+            generate: Some(S::generate),
+            ..self
+        }
+    }
+}
+
+/// How answers and diagnostics are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Output {
+    /// `Part N: ANSWER` lines, with warnings and errors on stderr.
+    #[default]
+    Text,
+    /// One JSON object per line on stdout for every answer or error.
+    Json,
+}
+
+/// Command line options shared by `aoc run` and the per-day binaries.
+pub struct Options {
+    /// Only this part is run when set, otherwise both are.
+    pub part: Option<u8>,
+    /// Input path, `-` for stdin; `input.txt` when unset.
+    pub input: Option<String>,
+    pub output: Output,
+    /// Fold over the input a line at a time instead of reading it whole.
+    pub stream: bool,
+    /// Where day specific details go; stderr when unset.
+    pub details: Option<String>,
+    pub flags: Flags,
+}
+
+impl Options {
+    /// Parses `[--part N] [--input PATH | PATH] [--output text|json]
+    /// [--stream] [--details PATH] [--NAME[=VALUE]...]`, keeping unrecognised
+    /// `--NAME` options as day specific flags.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, Error> {
+        let mut part = None;
+        let mut path = None;
+        let mut output = Output::Text;
+        let mut stream = false;
+        let mut details = None;
+        let mut flags = Flags::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().ok_or_else(|| usage("--part needs a value"))?;
+                    part = match value.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(usage(format!("unknown part `{}`", value))),
+                    };
+                }
+                "--input" => {
+                    path = Some(args.next().ok_or_else(|| usage("--input needs a value"))?);
+                }
+                "--output" => {
+                    let value = args.next().ok_or_else(|| usage("--output needs a value"))?;
+                    output = match value.as_str() {
+                        "text" => Output::Text,
+                        "json" => Output::Json,
+                        _ => return Err(usage(format!("unknown output `{}`", value))),
+                    };
+                }
+                "--stream" => stream = true,
+                "--details" => {
+                    details = Some(
+                        args.next()
+                            .ok_or_else(|| usage("--details needs a value"))?,
+                    );
+                }
+                flag if flag.starts_with("--") => flags.push(&flag[2..]),
+                _ if path.is_none() => path = Some(arg),
+                _ => return Err(usage(format!("unexpected argument `{}`", arg))),
+            }
+        }
+
+        Ok(Options {
+            part,
+            input: path,
+            output,
+            stream,
+            details,
+            flags,
+        })
+    }
+
+    pub(crate) fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// Day specific `--NAME` switches and `--NAME=VALUE` options.
+///
+/// Days take the flags they understand while parsing; anything left over is
+/// reported as an unknown option.
+#[derive(Debug, Clone, Default)]
+pub struct Flags(Vec<(String, Option<String>)>);
+
+impl Flags {
+    /// Parses arguments that must all be `--NAME[=VALUE]` flags.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, Error> {
+        let mut flags = Flags::default();
+        for arg in args {
+            match arg.strip_prefix("--") {
+                Some(flag) => flags.push(flag),
+                None => return Err(usage(format!("unexpected argument `{}`", arg))),
+            }
+        }
+        Ok(flags)
+    }
+
+    fn push(&mut self, flag: &str) {
+        let (name, value) = match flag.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_owned())),
+            None => (flag, None),
+        };
+        self.0.push((name.to_owned(), value));
+    }
+
+    fn take(&mut self, name: &str) -> Option<Option<String>> {
+        let i = self.0.iter().rposition(|(flag, _)| flag == name)?;
+        let (_, value) = self.0.remove(i);
+        self.0.retain(|(flag, _)| flag != name);
+        Some(value)
+    }
+
+    /// Removes `--NAME=VALUE` and parses its value.
+    pub fn value<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, Error> {
+        match self.take(name) {
+            None => Ok(None),
+            Some(None) => Err(usage(format!("--{} needs a value", name))),
+            Some(Some(value)) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| usage(format!("invalid value `{}` for --{}", value, name))),
+        }
+    }
+
+    /// Removes the `--NAME` switch, returning whether it was given.
+    pub fn switch(&mut self, name: &str) -> Result<bool, Error> {
+        match self.take(name) {
+            None => Ok(false),
+            Some(None) => Ok(true),
+            Some(Some(_)) => Err(usage(format!("--{} takes no value", name))),
+        }
+    }
+
+    /// Fails on the first flag not named in `known`, leaving every flag in
+    /// place.
+    pub fn check_known(&self, known: &[&str]) -> Result<(), Error> {
+        match self
+            .0
+            .iter()
+            .find(|(name, _)| !known.contains(&name.as_str()))
+        {
+            Some((name, _)) => Err(usage(format!("unknown option `--{}`", name))),
+            None => Ok(()),
+        }
+    }
+
+    /// Fails on the first flag nobody took.
+    pub fn finish(self) -> Result<(), Error> {
+        match self.0.first() {
+            Some((name, _)) => Err(usage(format!("unknown option `--{}`", name))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn usage(message: impl Into<String>) -> Error {
+    Error::Usage(message.into())
+}
+
+pub fn solve<S: Solution>(input: &S::Input, part: u8) -> Result<Answer, Error> {
+    match part {
+        1 => S::part1(input),
+        2 => S::part2(input),
+        _ => Err(usage(format!("unknown part `{}`", part))),
+    }
+}
+
+/// Parses the input once and prints the answer to every requested part.
+///
+/// Parts a day does not implement are skipped unless asked for explicitly.
+/// In JSON mode errors are printed as records too, otherwise they are left to
+/// the caller.
+pub fn run<S: Solution>(options: &Options) -> Result<(), Error> {
+    if options.stream {
+        let err = usage(format!("day {} cannot stream its input", S::DAY));
+        emit_error(options, S::DAY, None, &err);
+        return Err(err);
+    }
+
+    let start = Instant::now();
+    let input = load::<S>(options).inspect_err(|err| emit_error(options, S::DAY, None, err))?;
+    let parse_time = start.elapsed();
+    write_details(options, |out| S::details(&input, out))
+        .inspect_err(|err| emit_error(options, S::DAY, None, err))?;
+    answer_parts(options, S::DAY, parse_time, [Duration::ZERO; 2], |part| {
+        solve::<S>(&input, part)
+    })
+}
+
+/// Like `run`, but folds over the input a line at a time with `--stream`.
+pub fn run_streaming<S: Streaming>(options: &Options) -> Result<(), Error> {
+    if !options.stream {
+        return run::<S>(options);
+    }
+
+    let start = Instant::now();
+    let (state, folding) = fold::<S>(options, &options.parts())
+        .inspect_err(|err| emit_error(options, S::DAY, None, err))?;
+    let parse_time = start.elapsed().saturating_sub(folding.iter().sum());
+    write_details(options, |out| S::state_details(&state, out))
+        .inspect_err(|err| emit_error(options, S::DAY, None, err))?;
+    answer_parts(options, S::DAY, parse_time, folding, |part| {
+        S::answer(&state, part)
+    })
+}
+
+/// Feeds every line, or field, of the input named by `options` to a fresh
+/// state and folds it into each of `parts`, returning the state and the time
+/// each part spent folding.
+fn fold<S: Streaming>(options: &Options, parts: &[u8]) -> Result<(S::State, [Duration; 2]), Error> {
+    let mut flags = options.flags.clone();
+    let mut state = S::start(&mut flags)?;
+    flags.finish()?;
+    let mut folding = [Duration::ZERO; 2];
+    let source = Source::from_arg(options.input.as_deref());
+    let feed = |line: Line<'_>| {
+        let item = S::feed(&mut state, line)?;
+        for &part in parts {
+            let start = Instant::now();
+            S::fold(&mut state, &item, part);
+            folding[usize::from(part - 1)] += start.elapsed();
+        }
+        Ok(())
+    };
+    match S::SEPARATOR {
+        Some(separator) => source.for_each_field(separator, feed)?,
+        None => source.for_each_line(feed)?,
+    }
+    Ok((state, folding))
+}
+
+/// Writes the details `write` produces to the file named by `--details`, or
+/// to stderr, away from the answers on stdout.
+fn write_details(
+    options: &Options,
+    write: impl FnOnce(&mut dyn Write) -> io::Result<()>,
+) -> Result<(), Error> {
+    let (path, result) = match &options.details {
+        Some(path) => {
+            let result = fs::File::create(path).and_then(|file| {
+                let mut out = BufWriter::new(file);
+                write(&mut out)?;
+                out.flush()
+            });
+            (path.clone(), result)
+        }
+        None => {
+            let mut out = BufWriter::new(io::stderr().lock());
+            let result = write(&mut out).and_then(|()| out.flush());
+            ("<stderr>".to_owned(), result)
+        }
+    };
+    result.map_err(|source| Error::Io { path, source })
+}
+
+/// Prints the answer `solve` gives to every requested part, along with the
+/// warnings raised since the input was read.
+///
+/// `folding` is the time each part already spent solving while the input was
+/// streamed, counted with the time `solve` takes.
+fn answer_parts(
+    options: &Options,
+    day: u8,
+    parse_time: Duration,
+    folding: [Duration; 2],
+    solve: impl Fn(u8) -> Result<Answer, Error>,
+) -> Result<(), Error> {
+    let parse_warnings = warnings::take();
+    if options.output == Output::Text {
+        print_warnings(&parse_warnings);
+    }
+
+    for part in options.parts() {
+        let start = Instant::now();
+        let result = solve(part);
+        let solve_time = start.elapsed() + folding[usize::from(part - 1)];
+        let warnings = warnings::take();
+
+        match result {
+            Ok(answer) => match options.output {
+                Output::Text => {
+                    print_warnings(&warnings);
+                    println!("Part {}: {}", part, answer);
+                }
+                Output::Json => {
+                    let warnings = [parse_warnings.as_slice(), &warnings].concat();
+                    let record = Object::new()
+                        .field("day", &day)
+                        .field("part", &part)
+                        .field("answer", answer.as_str())
+                        .field("parse_ms", &millis(parse_time))
+                        .field("solve_ms", &millis(solve_time))
+                        .field("warnings", &warnings);
+                    println!("{}", record.finish());
+                }
+            },
+            Err(Error::Missing { .. }) if options.part.is_none() => {}
+            Err(err) => {
+                emit_error(options, day, Some(part), &err);
+                return Err(err);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1e6
+}
+
+fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
+}
+
+/// Prints `err` as a JSON record when JSON output was asked for.
+fn emit_error(options: &Options, day: u8, part: Option<u8>, err: &Error) {
+    if options.output != Output::Json {
+        return;
+    }
+
+    let mut error = Object::new();
+    error = match err {
+        Error::Parse(err) => error
+            .field("message", &err.kind.to_string())
+            .field("line", &err.line)
+            .field("column", &err.column)
+            .field("token", &err.token),
+        err => error
+            .field("message", &err.to_string())
+            .field("line", &None::<usize>)
+            .field("column", &None::<usize>)
+            .field("token", &None::<String>),
+    };
+    let record = Object::new()
+        .field("day", &day)
+        .field("part", &part)
+        .field("error", &error);
+    println!("{}", record.finish());
+}
+
+/// Solves a fixture and compares every recorded answer, printing a line per
+/// part. Returns whether all of them matched.
+pub fn check<S: Solution>(fixture: &Fixture) -> Result<bool, Error> {
+    let input = load::<S>(&fixture.options)?;
+    warnings::take();
+
+    let mut passed = true;
+    for (part, expected) in &fixture.answers {
+        let answer = solve::<S>(&input, *part)?;
+        warnings::take();
+        if answer.as_str() == expected {
+            println!("{} part {}: ok", fixture.name, part);
+        } else {
+            println!(
+                "{} part {}: expected {}, got {}",
+                fixture.name, part, expected, answer
+            );
+            passed = false;
+        }
+    }
+    Ok(passed)
+}
+
+/// Like `check`, and also checks that streaming the fixture gives the same
+/// answers.
+pub fn check_streaming<S: Streaming>(fixture: &Fixture) -> Result<bool, Error> {
+    let mut passed = check::<S>(fixture)?;
+
+    let parts: Vec<u8> = fixture.answers.iter().map(|&(part, _)| part).collect();
+    let (state, _) = fold::<S>(&fixture.options, &parts)?;
+    warnings::take();
+    for (part, expected) in &fixture.answers {
+        let answer = S::answer(&state, *part)?;
+        warnings::take();
+        if answer.as_str() != expected {
+            println!(
+                "{} part {} streamed: expected {}, got {}",
+                fixture.name, part, expected, answer
+            );
+            passed = false;
+        }
+    }
+    Ok(passed)
+}
+
+/// Reads and parses the input named by `options`, consuming its flags.
+///
+/// Unknown flags are reported before reading, which may wait on stdin.
+fn load<S: Solution>(options: &Options) -> Result<S::Input, Error> {
+    options.flags.check_known(S::FLAGS)?;
+    let text = Source::from_arg(options.input.as_deref()).read()?;
+    let mut flags = options.flags.clone();
+    let input = S::parse_with(&text, &mut flags).map_err(|err| err.with_snippet(&text))?;
+    flags.finish()?;
+    Ok(input)
+}
+
+/// Turns the outcome of a run into an exit code, printing the error on
+/// stderr for text output. JSON runs have already printed theirs as records.
+pub fn report(result: Result<(), Error>, output: Output) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            if output == Output::Text {
+                eprintln!("error: {}", err);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes a random input for `day` to stdout, taking `[--seed=N] [--size=N]
+/// [--answers=PATH]`; the answers known by construction go to `PATH`.
+pub fn generate(day: &Day, args: impl IntoIterator<Item = String>) -> Result<(), Error> {
+    let generator = day
+        .generate
+        .ok_or_else(|| usage(format!("day {} has no input generator", day.number)))?;
+    let mut flags = Flags::parse(args)?;
+    let seed = flags.value("seed")?.unwrap_or(1);
+    let size = flags.value("size")?.unwrap_or(1000);
+    let answers: Option<String> = flags.value("answers")?;
+    flags.finish()?;
+
+    let stdout_error = |source| Error::Io {
+        path: "<stdout>".to_owned(),
+        source,
+    };
+    let mut out = BufWriter::new(io::stdout().lock());
+    let known = generator(&mut Rng::new(seed), size, &mut out).map_err(stdout_error)?;
+    out.flush().map_err(stdout_error)?;
+
+    if let Some(path) = answers {
+        if known.is_empty() {
+            return Err(usage(format!(
+                "day {} does not know the answers to its generated inputs",
+                day.number
+            )));
+        }
+        let text: String = known
+            .iter()
+            .map(|(part, answer)| format!("part{}: {}\n", part, answer))
+            .collect();
+        fs::write(&path, text).map_err(|source| Error::Io { path, source })?;
+    }
+    Ok(())
+}
+
+/// Entry point of the per-day binaries: `generate [FLAGS...]` or the options
+/// of a run.
+pub fn main(day: Day) -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.next_if(|arg| arg == "generate").is_some() {
+        return report(generate(&day, args), Output::Text);
+    }
+
+    match Options::parse(args) {
+        Ok(options) => report((day.run)(&options), options.output),
+        Err(err) => report(Err(err), Output::Text),
+    }
+}
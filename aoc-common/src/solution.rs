@@ -0,0 +1,106 @@
+use std::{fmt, io};
+
+use crate::{Error, Line, ParseError, Rng, runner::Flags};
+
+/// A puzzle answer, kept as text so no integer width is imposed on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer(value.to_string())
+            }
+        })*
+    };
+}
+
+answer_from!(u32, u64, u128, usize, i32, i64, String);
+
+/// One day of the puzzle: how to parse its input and how to answer both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// The names of the day specific flags `parse_with` takes, so that
+    /// unknown ones are reported before the input is read.
+    const FLAGS: &[&str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    /// Parses the input, taking any day specific command line flags first.
+    fn parse_with(input: &str, _flags: &mut Flags) -> Result<Self::Input, Error> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error>;
+
+    /// Writes any day specific details the flags asked for, like a trace, once
+    /// the whole input has parsed. Runs write them to stderr or the file given
+    /// by `--details`; benchmarks skip them.
+    fn details(_input: &Self::Input, _out: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A day that can be answered in one pass over its input lines, so inputs far
+/// larger than memory can be streamed through it with `--stream`.
+pub trait Streaming: Solution {
+    /// Everything the answers need from the lines fed so far.
+    type State;
+
+    /// What `feed` reads from one line for `fold` to take into each part.
+    type Item;
+
+    /// Splits lines on this byte as well, feeding one field at a time, for
+    /// days whose input is a few very long lines.
+    const SEPARATOR: Option<u8> = None;
+
+    /// The state before the first line, taking day specific flags like
+    /// `Solution::parse_with`.
+    fn start(flags: &mut Flags) -> Result<Self::State, Error>;
+
+    /// Takes the next non-blank line, or field with `SEPARATOR`, doing only
+    /// the work every part needs.
+    fn feed(state: &mut Self::State, line: Line<'_>) -> Result<Self::Item, ParseError>;
+
+    /// Takes what `feed` read into the answer to `part`, once for each part
+    /// asked for, so parts nobody asked for are not solved. Runs count the
+    /// time spent here as solving.
+    fn fold(_state: &mut Self::State, _item: &Self::Item, _part: u8) {}
+
+    /// The answer to `part` once every line has been fed.
+    fn answer(state: &Self::State, part: u8) -> Result<Answer, Error>;
+
+    /// Like `Solution::details`, once every line has been fed.
+    fn state_details(_state: &Self::State, _out: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A day that can make up valid inputs of any size, for stress testing.
+pub trait Generate: Solution {
+    /// Writes an input of about `size` items drawn from `rng`, returning any
+    /// answers known by construction.
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>>;
+}
@@ -0,0 +1,25 @@
+use std::cell::RefCell;
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Notes something suspicious about the input that does not stop a day from
+/// answering, such as a device the puzzle asks about being absent.
+///
+/// Warnings are reported alongside the answer of the step that raised them;
+/// repeats of a message are only reported once.
+pub fn warn(message: impl Into<String>) {
+    let message = message.into();
+    WARNINGS.with(|warnings| {
+        let mut warnings = warnings.borrow_mut();
+        if !warnings.contains(&message) {
+            warnings.push(message);
+        }
+    });
+}
+
+/// Removes and returns the warnings raised on this thread so far.
+pub(crate) fn take() -> Vec<String> {
+    WARNINGS.with(|warnings| warnings.take())
+}
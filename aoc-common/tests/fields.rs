@@ -0,0 +1,40 @@
+//! Checks that streamed fields keep their place in the input.
+
+use aoc_common::Source;
+
+fn fields(input: &'static str) -> Vec<(usize, usize, String)> {
+    let mut fields = Vec::new();
+    Source::Embedded(input)
+        .for_each_field(b',', |field| {
+            fields.push((field.number, field.column, field.text.to_owned()));
+            Ok(())
+        })
+        .unwrap();
+    fields
+}
+
+#[test]
+fn fields_keep_their_lines_and_columns() {
+    assert_eq!(
+        fields("11-22, 95-115,\r\n\n998-1012,,x\nlast"),
+        [
+            (1, 1, "11-22".to_owned()),
+            (1, 8, "95-115".to_owned()),
+            (3, 1, "998-1012".to_owned()),
+            (3, 11, "x".to_owned()),
+            (4, 1, "last".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn fields_match_splitting_whole_lines() {
+    let input = " a,bb ,\tccc,,\nd\n\n ,e, f ";
+    let mut split = Vec::new();
+    for line in aoc_common::lines(input) {
+        for field in line.split(',') {
+            split.push((field.number, field.column, field.text.to_owned()));
+        }
+    }
+    assert_eq!(fields(input), split);
+}
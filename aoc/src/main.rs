@@ -0,0 +1,189 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use aoc_common::{
+    Error, Source, bench,
+    fixture::Fixture,
+    runner::{self, Day, Options, Output},
+};
+
+const USAGE: &str =
+    "aoc run <day|--all> [--part N] [--input PATH] [--output text|json] [--stream] [--details PATH]
+       aoc check <day|--all>
+       aoc bench <day|--all> [--part N] [--input PATH] [--iterations=N] [--json=PATH]
+       aoc generate <day> [--seed=N] [--size=N] [--answers=PATH]";
+
+fn day(day: u8) -> Option<Day> {
+    Some(match day {
+        1 => day1::day(),
+        2 => day2::day(),
+        3 => day3::day(),
+        4 => day4::day(),
+        5 => day5::day(),
+        6 => day6::day(),
+        7 => day7::day(),
+        8 => day8::day(),
+        9 => day9::day(),
+        10 => day10::day(),
+        11 => day11::day(),
+        12 => day12::day(),
+        _ => return None,
+    })
+}
+
+fn parse_day(arg: &str) -> Result<Day, Error> {
+    arg.parse()
+        .ok()
+        .and_then(day)
+        .ok_or_else(|| Error::Usage(format!("no such day `{}`", arg)))
+}
+
+/// The days named by `target`: a single day, or every day for `--all`.
+fn days(target: &str) -> Result<Vec<Day>, Error> {
+    if target == "--all" {
+        Ok((1..=12)
+            .map(|n| day(n).expect("every day has an entry"))
+            .collect())
+    } else {
+        Ok(vec![parse_day(target)?])
+    }
+}
+
+fn default_input(day: u8) -> String {
+    format!("day{}/input.txt", day)
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<ExitCode, Error> {
+    let command = args.next().ok_or_else(|| Error::Usage(USAGE.to_owned()))?;
+    let target = args.next().ok_or_else(|| Error::Usage(USAGE.to_owned()))?;
+
+    match command.as_str() {
+        "run" => run_days(&target, Options::parse(args)?),
+        "bench" => bench_days(&target, Options::parse(args)?),
+        "generate" => {
+            runner::generate(&parse_day(&target)?, args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        "check" => match args.next() {
+            Some(arg) => Err(Error::Usage(format!("unexpected argument `{}`", arg))),
+            None => check_days(&target),
+        },
+        _ => Err(Error::Usage(format!("unknown command `{}`", command))),
+    }
+}
+
+fn run_days(target: &str, mut options: Options) -> Result<ExitCode, Error> {
+    if target != "--all" {
+        let day = parse_day(target)?;
+        if options.input.is_none() && !Source::stdin_is_piped() {
+            options.input = Some(default_input(day.number));
+        }
+        return Ok(runner::report((day.run)(&options), options.output));
+    }
+
+    if options.input.is_some() {
+        return Err(Error::Usage(
+            "--input cannot be combined with --all".to_owned(),
+        ));
+    }
+
+    let mut code = ExitCode::SUCCESS;
+    for day in days(target)? {
+        let n = day.number;
+        options.input = Some(default_input(n));
+        if options.output == Output::Text {
+            println!("Day {}", n);
+        }
+        if let Err(err) = (day.run)(&options) {
+            if options.output == Output::Text {
+                eprintln!("error: day {}: {}", n, err);
+            }
+            code = ExitCode::FAILURE;
+        }
+    }
+    Ok(code)
+}
+
+/// Times every day in `target`, printing the results and optionally saving
+/// them as JSON.
+fn bench_days(target: &str, mut options: Options) -> Result<ExitCode, Error> {
+    let iterations = options.flags.value("iterations")?.unwrap_or(10);
+    if iterations == 0 {
+        return Err(Error::Usage("--iterations must be at least 1".to_owned()));
+    }
+    let json: Option<String> = options.flags.value("json")?;
+    if options.stream {
+        return Err(Error::Usage(
+            "--stream cannot be combined with bench".to_owned(),
+        ));
+    }
+
+    let single = target != "--all";
+    if !single && options.input.is_some() {
+        return Err(Error::Usage(
+            "--input cannot be combined with --all".to_owned(),
+        ));
+    }
+
+    let mut code = ExitCode::SUCCESS;
+    let mut results = Vec::new();
+    for day in days(target)? {
+        let n = day.number;
+        if !single || options.input.is_none() {
+            options.input = Some(default_input(n));
+        }
+        println!("Day {}", n);
+        match (day.bench)(&options, iterations) {
+            Ok(result) => {
+                println!("{}", result);
+                results.push(result);
+            }
+            Err(err) if single => return Err(err),
+            Err(err) => {
+                eprintln!("error: day {}: {}", n, err);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Some(path) = json {
+        fs::write(&path, bench::to_json(&results, iterations))
+            .map_err(|source| Error::Io { path, source })?;
+    }
+    Ok(code)
+}
+
+/// Checks every day in `target` against the fixtures in its directory.
+fn check_days(target: &str) -> Result<ExitCode, Error> {
+    let mut code = ExitCode::SUCCESS;
+    for day in days(target)? {
+        let n = day.number;
+        println!("Day {}", n);
+        match check_day(&day) {
+            Ok(true) => {}
+            Ok(false) => code = ExitCode::FAILURE,
+            Err(err) => {
+                eprintln!("error: day {}: {}", n, err);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    Ok(code)
+}
+
+fn check_day(day: &Day) -> Result<bool, Error> {
+    let dir = format!("day{}", day.number);
+    let fixtures = Fixture::load_dir(Path::new(&dir))?;
+    if fixtures.is_empty() {
+        return Err(Error::Usage(format!("no *.answers fixtures in {}", dir)));
+    }
+
+    let mut passed = true;
+    for fixture in &fixtures {
+        passed &= (day.check)(fixture)?;
+    }
+    Ok(passed)
+}
+
+fn main() -> ExitCode {
+    run(std::env::args().skip(1)).unwrap_or_else(|err| runner::report(Err(err), Output::Text))
+}
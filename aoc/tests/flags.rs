@@ -0,0 +1,80 @@
+//! Checks that mistyped or conflicting day flags are reported as usage errors.
+
+use std::{
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn unknown_flags_fail_before_stdin_ends() {
+    for stream in [false, true] {
+        let mut args = vec!["run", "2", "--input", "-", "--radixx=16"];
+        if stream {
+            args.push("--stream");
+        }
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(&args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("aoc binary runs");
+        // Keep stdin open, so only an early check lets `aoc` finish.
+        let _stdin = child.stdin.take();
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let status = loop {
+            if let Some(status) = child.try_wait().expect("aoc can be waited on") {
+                break status;
+            }
+            if Instant::now() > deadline {
+                child.kill().expect("aoc can be killed");
+                panic!("`aoc {}` waited for stdin", args.join(" "));
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        assert!(!status.success());
+    }
+}
+
+/// Runs `aoc run` on a day's example, returning whether it succeeded and what
+/// it wrote on stderr.
+fn run_example(day: u8, flags: &[&str]) -> (bool, String) {
+    let day = day.to_string();
+    let input = format!("../day{}/example.txt", day);
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", &day, "--input", &input])
+        .args(flags)
+        .stdin(Stdio::null())
+        .output()
+        .expect("aoc binary runs");
+    let stderr = String::from_utf8(output.stderr).expect("stderr is UTF-8");
+    (output.status.success(), stderr)
+}
+
+#[test]
+fn shown_banks_are_refused_when_streaming() {
+    let (ok, stderr) = run_example(3, &["--show=brackets"]);
+    assert!(ok, "{}", stderr);
+    let (ok, stderr) = run_example(3, &["--show=brackets", "--stream"]);
+    assert!(!ok);
+    assert!(
+        stderr.contains("--show cannot be combined with --stream"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn traces_are_refused_when_streaming() {
+    let (ok, stderr) = run_example(1, &["--trace=csv"]);
+    assert!(ok, "{}", stderr);
+    let (ok, stderr) = run_example(1, &["--trace=csv", "--stream"]);
+    assert!(!ok);
+    assert!(
+        stderr.contains("--trace cannot be combined with --stream"),
+        "{}",
+        stderr
+    );
+}
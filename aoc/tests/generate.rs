@@ -0,0 +1,50 @@
+//! Checks that generated inputs solve to the answers their generators know.
+
+use std::{fs, path::PathBuf, process::Command};
+
+fn aoc(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .expect("aoc binary runs");
+    assert!(
+        output.status.success(),
+        "`aoc {}` failed:\n{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("output is UTF-8")
+}
+
+fn generated(day: u8) {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let input = dir.join(format!("generated{}.txt", day));
+    let answers = dir.join(format!("generated{}.answers", day));
+
+    let text = aoc(&[
+        "generate",
+        &day.to_string(),
+        "--seed=42",
+        "--size=50",
+        &format!("--answers={}", answers.display()),
+    ]);
+    fs::write(&input, text).expect("input is written");
+
+    let output = aoc(&["run", &day.to_string(), "--input", input.to_str().unwrap()]);
+    let expected: String = fs::read_to_string(&answers)
+        .expect("answers are written")
+        .lines()
+        .map(|line| line.replace("part", "Part ") + "\n")
+        .collect();
+    assert_eq!(output, expected, "day {} disagrees with its generator", day);
+}
+
+#[test]
+fn day10() {
+    generated(10);
+}
+
+#[test]
+fn day12() {
+    generated(12);
+}
@@ -0,0 +1,90 @@
+//! Checks the records of `aoc run --output json`: their fields in order,
+//! answers as strings, error records and escaping.
+
+use std::{fs, path::PathBuf, process::Command, process::Output};
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .expect("aoc binary runs")
+}
+
+fn stdout_lines(output: &Output) -> Vec<String> {
+    String::from_utf8(output.stdout.clone())
+        .expect("output is UTF-8")
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn answers_are_one_record_per_part() {
+    let output = aoc(&[
+        "run",
+        "1",
+        "--input",
+        "../day1/example.txt",
+        "--output",
+        "json",
+        "--trace=json",
+    ]);
+    assert!(output.status.success());
+    // The trace goes to stderr, so stdout holds only the answer records.
+    let records = stdout_lines(&output);
+    assert_eq!(records.len(), 2, "{:?}", records);
+    for (record, (part, answer)) in records.iter().zip([(1, "3"), (2, "6")]) {
+        let head = format!(
+            "{{\"day\": 1, \"part\": {}, \"answer\": \"{}\", \"parse_ms\": ",
+            part, answer
+        );
+        assert!(record.starts_with(&head), "{}", record);
+        let (_, rest) = record.split_once(", \"solve_ms\": ").expect("solve_ms");
+        assert!(rest.ends_with(", \"warnings\": []}"), "{}", record);
+    }
+    assert!(!output.stderr.is_empty());
+}
+
+#[test]
+fn errors_are_records_with_escaped_tokens() {
+    let input = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("json-error.txt");
+    fs::write(&input, "11-22,\"x\ty\n").expect("input is written");
+    let output = aoc(&[
+        "run",
+        "2",
+        "--input",
+        input.to_str().unwrap(),
+        "--output",
+        "json",
+    ]);
+    assert!(!output.status.success());
+    assert!(output.stderr.is_empty());
+    assert_eq!(
+        stdout_lines(&output),
+        [concat!(
+            r#"{"day": 2, "part": null, "error": {"message": "range must look like `START-STOP`", "#,
+            r#""line": 1, "column": 7, "token": "\"x\ty"}}"#
+        )]
+    );
+}
+
+#[test]
+fn other_errors_have_no_position() {
+    let output = aoc(&[
+        "run",
+        "3",
+        "--input",
+        "../day3/example.txt",
+        "--output",
+        "json",
+        "--k=0",
+    ]);
+    assert!(!output.status.success());
+    assert_eq!(
+        stdout_lines(&output),
+        [concat!(
+            r#"{"day": 3, "part": null, "error": {"message": "usage: --k must be at least 1", "#,
+            r#""line": null, "column": null, "token": null}}"#
+        )]
+    );
+}
@@ -0,0 +1,46 @@
+//! Checks every day against the example and real inputs stored with their
+//! recorded answers as `dayN/*.answers` fixtures.
+
+use std::process::Command;
+
+fn check(day: u8) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["check", &day.to_string()])
+        .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+        .output()
+        .expect("aoc binary runs");
+
+    assert!(
+        output.status.success(),
+        "day {} does not match its fixtures:\n{}{}",
+        day,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+macro_rules! regression {
+    ($($name:ident => $day:expr),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day);
+            }
+        )*
+    };
+}
+
+regression! {
+    day01 => 1,
+    day02 => 2,
+    day03 => 3,
+    day04 => 4,
+    day05 => 5,
+    day06 => 6,
+    day07 => 7,
+    day08 => 8,
+    day09 => 9,
+    day10 => 10,
+    day11 => 11,
+    day12 => 12,
+}
@@ -0,0 +1,38 @@
+//! Checks that streamed runs solve only the parts asked for.
+
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn parts_not_asked_for_are_not_solved() {
+    // Thirty copies of one button light both lights in one press, but leave
+    // part 2 so many ways to reach its joltages that it never finishes.
+    let machine = format!("[##] {} {{20,20}}\n", vec!["(0,1)"; 30].join(" "));
+    let input = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("stream-slow-part2.txt");
+    fs::write(&input, machine).expect("input is written");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "10", "--part", "1", "--stream", "--input"])
+        .arg(&input)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("aoc binary runs");
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while child.try_wait().expect("aoc can be waited on").is_none() {
+        if Instant::now() > deadline {
+            child.kill().expect("aoc can be killed");
+            panic!("streaming part 1 solved part 2 as well");
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let output = child.wait_with_output().expect("aoc output is read");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Part 1: 1\n");
+}
@@ -0,0 +1,84 @@
+//! Working backwards from a password: which starts produce it, and what to
+//! append to reach it.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::{Dial, DialError, Rotation};
+
+/// Every start on a dial of `modulus` positions from which `rotations` pass 0
+/// exactly `target` times, in increasing order.
+pub fn starts_for(
+    modulus: i32,
+    rotations: &[Rotation],
+    target: u128,
+) -> Result<Vec<i32>, DialError> {
+    Dial::new(modulus, 0)?;
+    Ok((0..modulus)
+        .filter(|&start| {
+            let mut dial = Dial::new(modulus, start).expect("start is on the dial");
+            dial.rotate_all(rotations.iter().copied());
+            dial.zero_count == target
+        })
+        .collect())
+}
+
+/// Roughly the most memory, in bytes, `shortest_suffix` spends on the states
+/// it has reached.
+pub const MAX_SEARCH_BYTES: usize = 64 << 20;
+
+/// A dial position and the zero count gained since the search started.
+type State = (i32, usize);
+
+/// What remembering one reached state costs: its entry in the table of how
+/// it was reached and its place in the queue.
+const STATE_BYTES: usize = size_of::<(State, (State, usize))>() + size_of::<State>();
+
+/// The fewest rotations picked from `moves`, repeats allowed, that take the
+/// zero count of `dial` to exactly `target`.
+///
+/// Returns `None` when the dial has already passed `target` or `moves` cannot
+/// reach it. The search remembers every pair of dial position and count it
+/// reaches on the way, so it is meant for design sized targets: it also
+/// returns `None` rather than spend more than [`MAX_SEARCH_BYTES`] on them.
+pub fn shortest_suffix(dial: Dial, target: u128, moves: &[Rotation]) -> Option<Vec<Rotation>> {
+    let needed = usize::try_from(target.checked_sub(dial.zero_count)?).ok()?;
+    let max_states = MAX_SEARCH_BYTES / STATE_BYTES;
+
+    // How every state was first reached: the state before and the move.
+    let mut came_from: HashMap<State, (State, usize)> = HashMap::new();
+    let start = (dial.state, 0);
+    let mut queue = VecDeque::from([start]);
+    let mut found = None;
+
+    while let Some((state, gained)) = queue.pop_front() {
+        if gained == needed {
+            found = Some((state, gained));
+            break;
+        }
+        for (i, &rotation) in moves.iter().enumerate() {
+            let mut next = Dial::new(dial.modulus, state).expect("state is on the dial");
+            let total = gained as u128 + u128::from(next.rotate(rotation));
+            if total > needed as u128 {
+                continue;
+            }
+            let to = (next.state, total as usize);
+            if to == start || came_from.contains_key(&to) {
+                continue;
+            }
+            if came_from.len() == max_states {
+                return None;
+            }
+            came_from.insert(to, ((state, gained), i));
+            queue.push_back(to);
+        }
+    }
+
+    let mut at = found?;
+    let mut suffix = Vec::new();
+    while let Some(&(from, i)) = came_from.get(&at) {
+        suffix.push(moves[i]);
+        at = from;
+    }
+    suffix.reverse();
+    Some(suffix)
+}
@@ -0,0 +1,594 @@
+use std::{fmt, io, num::ParseIntError, str::FromStr};
+
+use aoc_common::{
+    Answer, Error, Generate, Line, ParseError, Rng, Solution, Streaming,
+    json::Object,
+    runner::{Day, Flags},
+};
+
+pub mod inverse;
+pub mod lock;
+
+use lock::{Lock, WheelRotation};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RotationError {
+    Empty,
+    UnknownDirection(char),
+    InvalidAmount(ParseIntError),
+    /// `-9223372036854775808` has no positive counterpart in an `i64`.
+    AmountOverflow,
+    /// A negative amount given to [`Rotation::new`].
+    NegativeAmount(i64),
+}
+
+impl fmt::Display for RotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RotationError::Empty => write!(f, "empty rotation"),
+            RotationError::UnknownDirection(c) => {
+                write!(
+                    f,
+                    "unknown direction `{}`, expected `L`, `R`, `+` or `-`",
+                    c
+                )
+            }
+            RotationError::InvalidAmount(err) => write!(f, "invalid amount: {}", err),
+            RotationError::AmountOverflow => write!(f, "amount is too large"),
+            RotationError::NegativeAmount(amount) => {
+                write!(f, "amount {} is negative", amount)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RotationError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn letter(self) -> char {
+        match self {
+            Direction::Right => 'R',
+            Direction::Left => 'L',
+        }
+    }
+
+    fn reversed(self) -> Self {
+        match self {
+            Direction::Right => Direction::Left,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = RotationError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'r' | 'R' => Ok(Direction::Right),
+            'l' | 'L' => Ok(Direction::Left),
+            _ => Err(RotationError::UnknownDirection(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    direction: Direction,
+    amount: i64,
+}
+
+/// Parses `L68`, `R48` and signed forms: `R-5` and `-5` both mean `L5`, `+5`
+/// means `R5`.
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let first = s
+            .chars()
+            .next()
+            .ok_or_else(|| ParseError::at(1, s, RotationError::Empty))?;
+        let (direction, column) = match Direction::try_from(first) {
+            Ok(direction) => (direction, 1 + first.len_utf8()),
+            Err(_) if first == '+' || first == '-' => (Direction::Right, 1),
+            Err(err) => return Err(ParseError::at(1, first, err)),
+        };
+
+        let amount_str = &s[column - 1..];
+        let amount = amount_str
+            .parse::<i64>()
+            .map_err(|err| ParseError::at(column, amount_str, RotationError::InvalidAmount(err)))?;
+        if amount >= 0 {
+            return Ok(Rotation { direction, amount });
+        }
+        let amount = amount
+            .checked_neg()
+            .ok_or_else(|| ParseError::at(column, amount_str, RotationError::AmountOverflow))?;
+        Ok(Rotation {
+            direction: direction.reversed(),
+            amount,
+        })
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.direction.letter(), self.amount)
+    }
+}
+
+impl Rotation {
+    /// A rotation of `amount` clicks, which must not be negative.
+    pub fn new(direction: Direction, amount: i64) -> Result<Self, RotationError> {
+        if amount < 0 {
+            return Err(RotationError::NegativeAmount(amount));
+        }
+        Ok(Rotation { direction, amount })
+    }
+}
+
+impl From<Rotation> for i64 {
+    fn from(value: Rotation) -> Self {
+        match value.direction {
+            Direction::Right => value.amount,
+            Direction::Left => -value.amount,
+        }
+    }
+}
+
+/// Whether `c` separates rotations written on the same line.
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || c == ','
+}
+
+/// The rotation tokens on `line`: separated by whitespace or commas and ending
+/// at a `#` comment.
+fn tokens(line: Line<'_>) -> impl Iterator<Item = Line<'_>> {
+    let mut rest = line.text.split('#').next().unwrap_or_default();
+    let mut column = line.column;
+    std::iter::from_fn(move || {
+        let skipped = rest.len() - rest.trim_start_matches(is_separator).len();
+        rest = &rest[skipped..];
+        column += skipped;
+        if rest.is_empty() {
+            return None;
+        }
+
+        let end = rest.find(is_separator).unwrap_or(rest.len());
+        let token = Line {
+            number: line.number,
+            column,
+            text: &rest[..end],
+        };
+        rest = &rest[end..];
+        column += end;
+        Some(token)
+    })
+}
+
+/// Parses every rotation in `input`, reporting the first bad token with its
+/// position.
+fn parse_rotations(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    aoc_common::lines(input)
+        .flat_map(tokens)
+        .map(|token| token.parse())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialError {
+    NoPositions(i32),
+    StartOffDial { start: i32, modulus: i32 },
+}
+
+impl fmt::Display for DialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DialError::NoPositions(modulus) => {
+                write!(f, "a dial needs at least 1 position, not {}", modulus)
+            }
+            DialError::StartOffDial { start, modulus } => write!(
+                f,
+                "start {} is not on a dial of positions 0 to {}",
+                start,
+                modulus - 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DialError {}
+
+/// Positions on the puzzle's dial.
+pub const MODULUS: i32 = 100;
+/// Where the puzzle's dial points before the first rotation.
+pub const START: i32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    modulus: i32,
+    state: i32,
+    /// Times the dial pointed at 0 during or at the end of a rotation.
+    pub zero_count: u128,
+    /// Rotations that left the dial at 0.
+    pub landed_count: u64,
+}
+
+impl Dial {
+    /// A dial of `modulus` positions pointing at `start`.
+    pub fn new(modulus: i32, start: i32) -> Result<Self, DialError> {
+        if modulus < 1 {
+            return Err(DialError::NoPositions(modulus));
+        }
+        if !(0..modulus).contains(&start) {
+            return Err(DialError::StartOffDial { start, modulus });
+        }
+        Ok(Dial {
+            modulus,
+            state: start,
+            zero_count: 0,
+            landed_count: 0,
+        })
+    }
+
+    /// Number of positions, `0..modulus`, on the wheel.
+    pub fn modulus(&self) -> i32 {
+        self.modulus
+    }
+
+    /// The position the dial points at, always in `0..modulus`.
+    pub fn state(&self) -> i32 {
+        self.state
+    }
+
+    /// The dial given by `--modulus=N` and `--start=N`, defaulting to the
+    /// puzzle's.
+    fn from_flags(flags: &mut Flags) -> Result<Self, Error> {
+        let modulus = flags.value("modulus")?.unwrap_or(MODULUS);
+        let start = flags.value("start")?.unwrap_or(START);
+        Dial::new(modulus, start).map_err(|err| Error::Usage(err.to_string()))
+    }
+
+    /// Applies `rotation`, returning how often it passed or landed on 0.
+    pub fn rotate(&mut self, rotation: Rotation) -> u64 {
+        // Wide enough that no amount up to `i64::MAX` can overflow.
+        let modulus = i128::from(self.modulus);
+        let amount = i128::from(rotation.amount);
+        // Clicks to the first 0 in the direction of travel, a whole turn when
+        // already there. Every `modulus` clicks from then on pass 0 again, so
+        // the passes are `(amount - to_zero) / modulus + 1` when `amount >=
+        // to_zero` and none otherwise; adding `modulus - to_zero` (in
+        // `0..modulus`) before dividing covers both cases at once.
+        let state = i128::from(self.state);
+        let to_zero = match rotation.direction {
+            Direction::Right => modulus - state,
+            Direction::Left if state == 0 => modulus,
+            Direction::Left => state,
+        };
+        let crossings = ((amount + modulus - to_zero) / modulus) as u64;
+        self.zero_count += u128::from(crossings);
+
+        let turned = state + i128::from(i64::from(rotation));
+        self.state = turned.rem_euclid(modulus) as i32;
+        if self.state == 0 {
+            self.landed_count += 1;
+        }
+        crossings
+    }
+
+    /// Applies `rotation` one click at a time, like [`Dial::rotate`] but slow
+    /// and obviously right; the reference `rotate` is tested against.
+    pub fn rotate_clicks(&mut self, rotation: Rotation) -> u64 {
+        let modulus = i64::from(self.modulus);
+        let step = match rotation.direction {
+            Direction::Right => 1,
+            Direction::Left => modulus - 1,
+        };
+        let mut crossings = 0;
+        for _ in 0..rotation.amount {
+            self.state = ((i64::from(self.state) + step) % modulus) as i32;
+            if self.state == 0 {
+                crossings += 1;
+            }
+        }
+
+        self.zero_count += u128::from(crossings);
+        if self.state == 0 {
+            self.landed_count += 1;
+        }
+        crossings
+    }
+
+    /// Turns the dial through every rotation in order, updating both counters
+    /// in the one pass.
+    pub fn rotate_all(&mut self, rotations: impl IntoIterator<Item = Rotation>) {
+        for rotation in rotations {
+            self.rotate(rotation);
+        }
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(MODULUS, START).expect("the puzzle's dial is valid")
+    }
+}
+
+/// How `--trace` writes its records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Csv,
+    Json,
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(TraceFormat::Csv),
+            "json" => Ok(TraceFormat::Json),
+            _ => Err(format!("unknown trace format `{}`", s)),
+        }
+    }
+}
+
+/// The rotations `--trace=csv|json` asked to record, with the input lines
+/// they came from, written as details once the whole input has been read.
+struct Trace {
+    format: TraceFormat,
+    /// The dial before the first rotation.
+    dial: Dial,
+    steps: Vec<(usize, Rotation)>,
+}
+
+impl Trace {
+    fn from_flags(flags: &mut Flags, dial: Dial) -> Result<Option<Self>, Error> {
+        let format = flags.value("trace")?;
+        Ok(format.map(|format| Trace {
+            format,
+            dial,
+            steps: Vec::new(),
+        }))
+    }
+
+    /// Writes a record of every step: the line, the rotation, the dial
+    /// before and after it and how often it passed 0.
+    fn write(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        if self.format == TraceFormat::Csv {
+            writeln!(out, "line,direction,amount,before,after,crossings")?;
+        }
+        let mut dial = self.dial;
+        for &(line, rotation) in &self.steps {
+            let before = dial.state;
+            let crossings = dial.rotate(rotation);
+            let direction = rotation.direction.letter();
+            match self.format {
+                TraceFormat::Csv => writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    line, direction, rotation.amount, before, dial.state, crossings
+                )?,
+                TraceFormat::Json => writeln!(
+                    out,
+                    "{}",
+                    Object::new()
+                        .field("line", &line)
+                        .field("direction", &direction.to_string())
+                        .field("amount", &rotation.amount)
+                        .field("before", &before)
+                        .field("after", &dial.state)
+                        .field("crossings", &crossings)
+                        .finish()
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A dial, or the lock `--wheels` asked for, being turned line by line, with
+/// a copy for each part so only the parts asked for turn theirs.
+pub struct Turns {
+    dials: [Dial; 2],
+    locks: Option<[Lock; 2]>,
+    /// Whether each part has turned its copy.
+    turned: [bool; 2],
+}
+
+/// The parsed input: the rotations and the dial they start on, or the lock
+/// and the wheel rotations given with `--wheels`.
+pub struct Rotations {
+    dial: Dial,
+    rotations: Vec<Rotation>,
+    trace: Option<Trace>,
+    lock: Option<(Lock, Vec<WheelRotation>)>,
+}
+
+/// The trace and the lock the flags ask for, which cannot be combined.
+fn trace_or_lock(flags: &mut Flags, dial: Dial) -> Result<(Option<Trace>, Option<Lock>), Error> {
+    let trace = Trace::from_flags(flags, dial)?;
+    let lock = Lock::from_flags(flags, dial)?;
+    if trace.is_some() && lock.is_some() {
+        return Err(Error::Usage(
+            "--trace cannot be combined with --wheels".to_owned(),
+        ));
+    }
+    Ok((trace, lock))
+}
+
+fn turn_all(input: &Rotations) -> Dial {
+    let mut dial = input.dial;
+    dial.rotate_all(input.rotations.iter().copied());
+    dial
+}
+
+fn turn_lock((lock, rotations): &(Lock, Vec<WheelRotation>)) -> Lock {
+    let mut lock = lock.clone();
+    lock.rotate_all(rotations.iter().copied());
+    lock
+}
+
+impl Solution for Dial {
+    const DAY: u8 = 1;
+
+    type Input = Rotations;
+
+    const FLAGS: &[&str] = &["modulus", "start", "trace", "wheels", "carry"];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Rotations {
+            dial: Dial::default(),
+            rotations: parse_rotations(input)?,
+            trace: None,
+            lock: None,
+        })
+    }
+
+    /// Takes `--modulus`, `--start`, `--trace`, `--wheels` and `--carry`.
+    fn parse_with(input: &str, flags: &mut Flags) -> Result<Self::Input, Error> {
+        let dial = Dial::from_flags(flags)?;
+        let (trace, lock) = trace_or_lock(flags, dial)?;
+        if let Some(lock) = lock {
+            let rotations = lock::parse_wheel_rotations(input, lock.wheels().len())?;
+            return Ok(Rotations {
+                dial,
+                rotations: Vec::new(),
+                trace: None,
+                lock: Some((lock, rotations)),
+            });
+        }
+        let Some(mut trace) = trace else {
+            return Ok(Rotations {
+                dial,
+                ..Self::parse(input)?
+            });
+        };
+
+        let mut rotations = Vec::new();
+        for token in aoc_common::lines(input).flat_map(tokens) {
+            let rotation = token.parse()?;
+            trace.steps.push((token.number, rotation));
+            rotations.push(rotation);
+        }
+        Ok(Rotations {
+            dial,
+            rotations,
+            trace: Some(trace),
+            lock: None,
+        })
+    }
+
+    /// Rotations that left a wheel at 0, summed over a lock's wheels.
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(match &input.lock {
+            Some(lock) => turn_lock(lock).landed_count().into(),
+            None => turn_all(input).landed_count.into(),
+        })
+    }
+
+    /// Passes of 0, summed over a lock's wheels.
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(match &input.lock {
+            Some(lock) => turn_lock(lock).zero_count().into(),
+            None => turn_all(input).zero_count.into(),
+        })
+    }
+
+    /// Writes the trace, or each wheel's counts for a lock.
+    fn details(input: &Self::Input, out: &mut dyn io::Write) -> io::Result<()> {
+        match (&input.trace, &input.lock) {
+            (Some(trace), _) => trace.write(out),
+            (None, Some(lock)) => turn_lock(lock).write_wheels(out),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// `--trace` is refused, as it would keep every step until the input ends.
+impl Streaming for Dial {
+    type State = Turns;
+
+    type Item = Vec<WheelRotation>;
+
+    fn start(flags: &mut Flags) -> Result<Self::State, Error> {
+        let dial = Dial::from_flags(flags)?;
+        let (trace, lock) = trace_or_lock(flags, dial)?;
+        if trace.is_some() {
+            return Err(Error::Usage(
+                "--trace cannot be combined with --stream".to_owned(),
+            ));
+        }
+        Ok(Turns {
+            dials: [dial; 2],
+            locks: lock.map(|lock| [lock.clone(), lock]),
+            turned: [false; 2],
+        })
+    }
+
+    /// Reads the rotations on `line`, all for the first wheel without a lock.
+    fn feed(state: &mut Self::State, line: Line<'_>) -> Result<Self::Item, ParseError> {
+        let wheels = state.locks.as_ref().map(|locks| locks[0].wheels().len());
+        tokens(line)
+            .map(|token| match wheels {
+                Some(wheels) => lock::wheel_rotation(token, wheels),
+                None => Ok(WheelRotation {
+                    wheel: 0,
+                    rotation: token.parse()?,
+                }),
+            })
+            .collect()
+    }
+
+    fn fold(state: &mut Self::State, rotations: &Self::Item, part: u8) {
+        let i = usize::from(part - 1);
+        state.turned[i] = true;
+        match &mut state.locks {
+            Some(locks) => locks[i].rotate_all(rotations.iter().copied()),
+            None => state.dials[i].rotate_all(rotations.iter().map(|turn| turn.rotation)),
+        }
+    }
+
+    fn answer(state: &Self::State, part: u8) -> Result<Answer, Error> {
+        Ok(match (part, &state.locks) {
+            (1, Some(locks)) => locks[0].landed_count().into(),
+            (_, Some(locks)) => locks[1].zero_count().into(),
+            (1, None) => state.dials[0].landed_count.into(),
+            (_, None) => state.dials[1].zero_count.into(),
+        })
+    }
+
+    fn state_details(state: &Self::State, out: &mut dyn io::Write) -> io::Result<()> {
+        match &state.locks {
+            Some([first, _]) if state.turned[0] => first.write_wheels(out),
+            Some([_, second]) => second.write_wheels(out),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes `size` rotations of up to 999 clicks either way.
+impl Generate for Dial {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        for _ in 0..size {
+            let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+            writeln!(out, "{}{}", direction, rng.range(1..=999))?;
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day1` binary.
+pub fn day() -> Day {
+    Day::streaming::<Dial>().generating::<Dial>()
+}
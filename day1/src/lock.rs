@@ -0,0 +1,283 @@
+//! Combination locks of several dials coupled like an odometer.
+
+use std::{fmt, io, num::ParseIntError, str::FromStr};
+
+use aoc_common::{Error, Line, ParseError, lines, runner::Flags};
+
+use crate::{Dial, Direction, Rotation, tokens};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockError {
+    InvalidWheel(ParseIntError),
+    WheelZero,
+    NoSuchWheel {
+        wheel: usize,
+        wheels: usize,
+    },
+    UnknownCarry(String),
+    /// A lock needs one carry rule between each pair of neighbouring wheels.
+    CarryCount {
+        carries: usize,
+        wheels: usize,
+    },
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LockError::InvalidWheel(err) => write!(f, "invalid wheel: {}", err),
+            LockError::WheelZero => write!(f, "wheels are counted from 1"),
+            LockError::NoSuchWheel { wheel, wheels } => {
+                write!(f, "no wheel {}, the lock has wheels 1 to {}", wheel, wheels)
+            }
+            LockError::UnknownCarry(carry) => write!(
+                f,
+                "unknown carry `{}`, expected `free`, `forward[:N]` or `reverse[:N]`",
+                carry
+            ),
+            LockError::CarryCount { carries, wheels } => write!(
+                f,
+                "{} carry rules for {} wheels, expected one per neighbouring pair",
+                carries, wheels
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LockError {}
+
+/// What a wheel wrapping past 0 does to the wheel after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Carry {
+    /// Nothing; the wheels turn independently.
+    Free,
+    /// Turns it this many clicks the same way, like an odometer.
+    Forward(i64),
+    /// Turns it this many clicks the other way, like meshed gears.
+    Reverse(i64),
+}
+
+impl Carry {
+    /// The rotation the next wheel makes when one turned `direction` wrapped
+    /// past 0 `wraps` times.
+    fn carried(self, direction: Direction, wraps: u64) -> Option<Rotation> {
+        let (direction, clicks) = match self {
+            Carry::Free => return None,
+            Carry::Forward(clicks) => (direction, clicks),
+            Carry::Reverse(clicks) => (direction.reversed(), clicks),
+        };
+        let amount = i64::try_from(wraps)
+            .unwrap_or(i64::MAX)
+            .saturating_mul(clicks);
+        (amount > 0).then_some(Rotation { direction, amount })
+    }
+}
+
+/// Parses `free`, `forward`, `reverse` and `forward:N` or `reverse:N` for `N`
+/// clicks per crossing.
+impl FromStr for Carry {
+    type Err = LockError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || LockError::UnknownCarry(s.to_owned());
+        if s == "free" {
+            return Ok(Carry::Free);
+        }
+        let (rule, clicks) = match s.split_once(':') {
+            Some((rule, clicks)) => (rule, clicks.parse().map_err(|_| unknown())?),
+            None => (s, 1),
+        };
+        match rule {
+            "forward" if clicks >= 0 => Ok(Carry::Forward(clicks)),
+            "reverse" if clicks >= 0 => Ok(Carry::Reverse(clicks)),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+/// A rotation of one wheel of a lock, written `WHEEL:ROTATION` with wheels
+/// counted from 1, or just `ROTATION` for the first wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WheelRotation {
+    /// Index of the wheel, from 0.
+    pub wheel: usize,
+    pub rotation: Rotation,
+}
+
+impl FromStr for WheelRotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((wheel_str, rotation_str)) = s.split_once(':') else {
+            return Ok(WheelRotation {
+                wheel: 0,
+                rotation: s.parse()?,
+            });
+        };
+
+        let wheel: usize = wheel_str
+            .parse()
+            .map_err(|err| ParseError::at(1, wheel_str, LockError::InvalidWheel(err)))?;
+        let column = wheel_str.len() + 2;
+        let rotation: Rotation = rotation_str.parse().map_err(|mut err: ParseError| {
+            err.column += column - 1;
+            err
+        })?;
+        match wheel.checked_sub(1) {
+            Some(wheel) => Ok(WheelRotation { wheel, rotation }),
+            None => Err(ParseError::at(1, wheel_str, LockError::WheelZero)),
+        }
+    }
+}
+
+impl fmt::Display for WheelRotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.wheel + 1, self.rotation)
+    }
+}
+
+/// Parses the wheel rotations in `input`, written like plain rotations, and
+/// checks each names one of `wheels` wheels.
+pub fn parse_wheel_rotations(input: &str, wheels: usize) -> Result<Vec<WheelRotation>, ParseError> {
+    lines(input)
+        .flat_map(tokens)
+        .map(|token| wheel_rotation(token, wheels))
+        .collect()
+}
+
+/// Parses one token of wheel rotations, checking it names one of `wheels`.
+pub(crate) fn wheel_rotation(token: Line<'_>, wheels: usize) -> Result<WheelRotation, ParseError> {
+    let rotation: WheelRotation = token.parse()?;
+    if rotation.wheel >= wheels {
+        let wheel = rotation.wheel + 1;
+        return Err(ParseError::new(
+            token.number,
+            token.column,
+            token.text,
+            LockError::NoSuchWheel { wheel, wheels },
+        ));
+    }
+    Ok(rotation)
+}
+
+/// Dials side by side, where a wheel wrapping past 0 may turn the next one.
+///
+/// Like an odometer, a wheel turned right carries when it goes from the last
+/// position to 0 and one turned left when it goes from 0 to the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    wheels: Vec<Dial>,
+    /// The rule between each wheel and the next.
+    carries: Vec<Carry>,
+}
+
+impl Lock {
+    /// A lock with the same `carry` rule between every pair of wheels.
+    pub fn new(wheels: Vec<Dial>, carry: Carry) -> Self {
+        let carries = vec![carry; wheels.len().saturating_sub(1)];
+        Lock { wheels, carries }
+    }
+
+    /// A lock with `carries[i]` between wheels `i` and `i + 1`.
+    pub fn with_carries(wheels: Vec<Dial>, carries: Vec<Carry>) -> Result<Self, LockError> {
+        if carries.len() != wheels.len().saturating_sub(1) {
+            return Err(LockError::CarryCount {
+                carries: carries.len(),
+                wheels: wheels.len(),
+            });
+        }
+        Ok(Lock { wheels, carries })
+    }
+
+    /// The lock given by `--wheels=N` and `--carry=RULE[,RULE...]`, with
+    /// every wheel a copy of `dial`, or `None` without `--wheels`.
+    ///
+    /// One rule is used between every pair of wheels, and the default is
+    /// `forward`.
+    pub(crate) fn from_flags(flags: &mut Flags, dial: Dial) -> Result<Option<Self>, Error> {
+        let carry: Option<String> = flags.value("carry")?;
+        let Some(wheels) = flags.value::<usize>("wheels")? else {
+            return match carry {
+                Some(_) => Err(Error::Usage("--carry needs --wheels".to_owned())),
+                None => Ok(None),
+            };
+        };
+        if wheels == 0 {
+            return Err(Error::Usage("--wheels must be at least 1".to_owned()));
+        }
+        let usage = |err: LockError| Error::Usage(err.to_string());
+        let carries = carry
+            .as_deref()
+            .unwrap_or("forward")
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<Vec<Carry>, _>>()
+            .map_err(usage)?;
+        let wheels = vec![dial; wheels];
+        match carries[..] {
+            [carry] => Ok(Some(Lock::new(wheels, carry))),
+            _ => Lock::with_carries(wheels, carries).map(Some).map_err(usage),
+        }
+    }
+
+    /// The wheels, first to last, each counting its own passes and landings
+    /// on 0, carries included.
+    pub fn wheels(&self) -> &[Dial] {
+        &self.wheels
+    }
+
+    /// Turns `wheel` by `rotation` and carries into the wheels after it.
+    ///
+    /// Panics if the lock has no such wheel.
+    pub fn rotate(&mut self, wheel: usize, rotation: Rotation) {
+        let mut rotation = Some(rotation);
+        for (i, dial) in (wheel..).zip(&mut self.wheels[wheel..]) {
+            let Some(turn) = rotation else {
+                break;
+            };
+            let left_zero = dial.state == 0;
+            let arrivals = dial.rotate(turn);
+            // Arriving at 0 going left is not a wrap, but leaving it is.
+            let wraps = match turn.direction {
+                Direction::Right => arrivals,
+                Direction::Left => arrivals + u64::from(left_zero) - u64::from(dial.state == 0),
+            };
+            rotation = self
+                .carries
+                .get(i)
+                .and_then(|carry| carry.carried(turn.direction, wraps));
+        }
+    }
+
+    pub fn rotate_all(&mut self, rotations: impl IntoIterator<Item = WheelRotation>) {
+        for WheelRotation { wheel, rotation } in rotations {
+            self.rotate(wheel, rotation);
+        }
+    }
+
+    /// Rotations, carries included, that left some wheel at 0.
+    pub fn landed_count(&self) -> u64 {
+        self.wheels.iter().map(|dial| dial.landed_count).sum()
+    }
+
+    /// Times any wheel pointed at 0 during or at the end of a rotation.
+    pub fn zero_count(&self) -> u128 {
+        self.wheels.iter().map(|dial| dial.zero_count).sum()
+    }
+
+    /// Writes a CSV record of where each wheel ended up and its counts.
+    pub fn write_wheels(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(out, "wheel,state,zero_count,landed_count")?;
+        for (i, dial) in self.wheels.iter().enumerate() {
+            writeln!(
+                out,
+                "{},{},{},{}",
+                i + 1,
+                dial.state,
+                dial.zero_count,
+                dial.landed_count
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by the integration tests; each uses only some of them.
+#![allow(dead_code)]
+
+use aoc_common::Rng;
+use day1::{Direction, Rotation};
+
+pub fn random_direction(rng: &mut Rng) -> Direction {
+    if rng.chance(0.5) {
+        Direction::Left
+    } else {
+        Direction::Right
+    }
+}
+
+pub fn random_rotation(rng: &mut Rng, max_amount: u64) -> Rotation {
+    Rotation::new(random_direction(rng), rng.range(0..=max_amount) as i64).unwrap()
+}
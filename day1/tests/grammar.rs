@@ -0,0 +1,100 @@
+//! Checks the forms rotations may be written in and where bad ones are
+//! reported.
+
+mod common;
+
+use aoc_common::{Error, Rng, Solution};
+use common::random_direction;
+use day1::{Dial, Direction, Rotation, RotationError};
+
+fn rotation(s: &str) -> Rotation {
+    s.parse()
+        .unwrap_or_else(|err| panic!("`{}` does not parse: {}", s, err))
+}
+
+/// The line, column and kind of the error parsing `input` fails with.
+fn error(input: &str) -> (usize, usize, RotationError) {
+    match Dial::parse(input) {
+        Err(Error::Parse(err)) => {
+            let kind = err
+                .kind::<RotationError>()
+                .expect("a rotation error")
+                .clone();
+            (err.line, err.column, kind)
+        }
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("{:?} parses", input),
+    }
+}
+
+#[test]
+fn signed_forms_mean_the_same_rotation() {
+    let left = Rotation::new(Direction::Left, 5).unwrap();
+    let right = Rotation::new(Direction::Right, 5).unwrap();
+    for s in ["L5", "l5", "L+5", "R-5", "-5"] {
+        assert_eq!(rotation(s), left, "{}", s);
+    }
+    for s in ["R5", "r5", "R+5", "L-5", "+5"] {
+        assert_eq!(rotation(s), right, "{}", s);
+    }
+    assert_eq!(
+        rotation("R9223372036854775807"),
+        Rotation::new(Direction::Right, i64::MAX).unwrap()
+    );
+}
+
+#[test]
+fn rotations_round_trip_through_display() {
+    let mut rng = Rng::new(3);
+    for _ in 0..10_000 {
+        let amount = (rng.next_u64() >> rng.below(64)) as i64 & i64::MAX;
+        let original = Rotation::new(random_direction(&mut rng), amount).unwrap();
+        assert_eq!(rotation(&original.to_string()), original);
+    }
+}
+
+#[test]
+fn separators_comments_and_blank_lines_are_skipped() {
+    let spread =
+        "# the example, spread out\nL68, L30  R48\n\nL5,R60 # two more\n\t-55 L1 L99,+14,L82\n";
+    let example = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt"))
+        .expect("example input exists");
+    for part in [1, 2] {
+        let solve = if part == 1 { Dial::part1 } else { Dial::part2 };
+        assert_eq!(
+            solve(&Dial::parse(spread).unwrap()).unwrap(),
+            solve(&Dial::parse(&example).unwrap()).unwrap(),
+            "part {}",
+            part
+        );
+    }
+}
+
+#[test]
+fn bad_tokens_are_reported_where_they_are() {
+    assert_eq!(
+        error("L5\nR3, X4"),
+        (2, 5, RotationError::UnknownDirection('X'))
+    );
+    assert!(matches!(
+        error("L5 R  L2"),
+        (1, 5, RotationError::InvalidAmount(_))
+    ));
+    assert!(matches!(
+        error("  R99999999999999999999"),
+        (1, 4, RotationError::InvalidAmount(_))
+    ));
+    assert_eq!(
+        error("L-9223372036854775808"),
+        (1, 2, RotationError::AmountOverflow)
+    );
+}
+
+#[test]
+fn negative_amounts_are_not_rotations() {
+    assert_eq!(
+        Rotation::new(Direction::Left, -5),
+        Err(RotationError::NegativeAmount(-5))
+    );
+    assert!(Rotation::new(Direction::Left, 0).is_ok());
+}
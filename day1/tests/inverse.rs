@@ -0,0 +1,101 @@
+//! Checks the inverse solvers against plain simulation.
+
+mod common;
+
+use aoc_common::Rng;
+use common::random_rotation;
+use day1::{
+    Dial, Direction, Rotation,
+    inverse::{shortest_suffix, starts_for},
+};
+
+fn zero_count(mut dial: Dial, rotations: &[Rotation]) -> u128 {
+    dial.rotate_all(rotations.iter().copied());
+    dial.zero_count
+}
+
+/// Whether some sequence of exactly `len` moves takes `dial` to `target`.
+fn reachable_in(dial: Dial, target: u128, moves: &[Rotation], len: usize) -> bool {
+    if len == 0 {
+        return dial.zero_count == target;
+    }
+    moves.iter().any(|&rotation| {
+        let mut next = dial;
+        next.rotate(rotation);
+        next.zero_count <= target && reachable_in(next, target, moves, len - 1)
+    })
+}
+
+#[test]
+fn starts_for_the_example_include_the_puzzle_start() {
+    let example = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt"))
+        .expect("example input exists");
+    let rotations: Vec<Rotation> = example
+        .split_whitespace()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+    let starts = starts_for(100, &rotations, 6).unwrap();
+    assert!(starts.contains(&50), "{:?}", starts);
+    for start in 0..100 {
+        let count = zero_count(Dial::new(100, start).unwrap(), &rotations);
+        assert_eq!(starts.contains(&start), count == 6, "start {}", start);
+    }
+    assert!(starts_for(0, &rotations, 6).is_err());
+}
+
+#[test]
+fn shortest_suffixes_are_shortest() {
+    let mut rng = Rng::new(4);
+    for _ in 0..300 {
+        let modulus = rng.range(1..=8) as i32;
+        let start = rng.below(modulus as u64) as i32;
+        let mut dial = Dial::new(modulus, start).unwrap();
+        dial.zero_count = u128::from(rng.below(3));
+        let moves: Vec<Rotation> = (0..rng.range(1..=3))
+            .map(|_| random_rotation(&mut rng, 10))
+            .collect();
+        let target = u128::from(rng.below(6));
+
+        match shortest_suffix(dial, target, &moves) {
+            Some(suffix) => {
+                assert_eq!(zero_count(dial, &suffix), target);
+                for len in 0..suffix.len() {
+                    assert!(
+                        !reachable_in(dial, target, &moves, len),
+                        "{:?} is not shortest",
+                        suffix
+                    );
+                }
+            }
+            None => {
+                for len in 0..=6 {
+                    assert!(
+                        !reachable_in(dial, target, &moves, len),
+                        "{:?} {:?} can reach {}",
+                        dial,
+                        moves,
+                        target
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn oversized_searches_give_up() {
+    let dial = Dial::new(i32::MAX, 0).unwrap();
+    let moves = [Rotation::new(Direction::Right, 1).unwrap()];
+    assert_eq!(shortest_suffix(dial, 2, &moves), None);
+    assert_eq!(shortest_suffix(Dial::default(), u128::MAX, &moves), None);
+    assert_eq!(
+        shortest_suffix(Dial::new(10, 9).unwrap(), 1, &moves),
+        Some(moves.to_vec())
+    );
+    // Huge dials are fine as long as the search itself stays small.
+    assert_eq!(
+        shortest_suffix(Dial::new(i32::MAX, i32::MAX - 1).unwrap(), 1, &moves),
+        Some(moves.to_vec())
+    );
+}
@@ -0,0 +1,151 @@
+//! Checks locks of coupled dials and how rotations address their wheels.
+
+mod common;
+
+use aoc_common::Rng;
+use common::random_rotation;
+use day1::{
+    Dial, Direction, Rotation,
+    lock::{Carry, Lock, LockError, WheelRotation, parse_wheel_rotations},
+};
+
+fn lock(wheels: usize, modulus: i32, carry: Carry) -> Lock {
+    Lock::new(vec![Dial::new(modulus, 0).unwrap(); wheels], carry)
+}
+
+#[test]
+fn forward_carry_counts_like_an_odometer() {
+    let mut rng = Rng::new(5);
+    let mut odometer = lock(4, 10, "forward".parse().unwrap());
+    let mut total = 0;
+    for _ in 0..200 {
+        let amount = rng.range(0..=50);
+        total += amount;
+        odometer.rotate(0, Rotation::new(Direction::Right, amount as i64).unwrap());
+
+        let reading: Vec<i32> = odometer.wheels().iter().map(|dial| dial.state()).collect();
+        let digits: Vec<i32> = (0..4)
+            .map(|i| (total / 10_u64.pow(i) % 10) as i32)
+            .collect();
+        assert_eq!(reading, digits, "after {} clicks", total);
+    }
+}
+
+#[test]
+fn forward_carry_counts_down_like_an_odometer() {
+    let mut rng = Rng::new(17);
+    let mut odometer = lock(4, 10, "forward".parse().unwrap());
+    // Start at 9999, below which the count never falls.
+    odometer.rotate(0, Rotation::new(Direction::Left, 1).unwrap());
+    let mut total: u64 = 9_999;
+    for _ in 0..200 {
+        let amount = rng.range(0..=total.min(50));
+        total -= amount;
+        odometer.rotate(0, Rotation::new(Direction::Left, amount as i64).unwrap());
+
+        let reading: Vec<i32> = odometer.wheels().iter().map(|dial| dial.state()).collect();
+        let digits: Vec<i32> = (0..4)
+            .map(|i| (total / 10_u64.pow(i) % 10) as i32)
+            .collect();
+        assert_eq!(reading, digits, "at {}", total);
+    }
+}
+
+#[test]
+fn left_turns_borrow_only_when_leaving_zero() {
+    let mut counter = lock(2, 10, Carry::Forward(1));
+    counter.rotate(0, Rotation::new(Direction::Right, 11).unwrap());
+    counter.rotate(0, Rotation::new(Direction::Left, 1).unwrap());
+    let reading: Vec<i32> = counter.wheels().iter().map(|dial| dial.state()).collect();
+    assert_eq!(reading, [0, 1]);
+    counter.rotate(0, Rotation::new(Direction::Left, 1).unwrap());
+    let reading: Vec<i32> = counter.wheels().iter().map(|dial| dial.state()).collect();
+    assert_eq!(reading, [9, 0]);
+}
+
+#[test]
+fn each_pair_of_wheels_has_its_own_carry() {
+    let wheels = vec![Dial::new(10, 0).unwrap(); 3];
+    let carries = vec![Carry::Forward(1), Carry::Reverse(2)];
+    let mut mixed = Lock::with_carries(wheels.clone(), carries).unwrap();
+    mixed.rotate(0, Rotation::new(Direction::Right, 100).unwrap());
+    let reading: Vec<i32> = mixed.wheels().iter().map(|dial| dial.state()).collect();
+    assert_eq!(reading, [0, 0, 8]);
+
+    assert_eq!(
+        Lock::with_carries(wheels, vec![Carry::Free]),
+        Err(LockError::CarryCount {
+            carries: 1,
+            wheels: 3
+        })
+    );
+}
+
+#[test]
+fn free_wheels_turn_independently() {
+    let mut rng = Rng::new(6);
+    let mut free = lock(3, 7, Carry::Free);
+    let mut alone = [Dial::new(7, 0).unwrap(); 3];
+    for _ in 0..200 {
+        let wheel = rng.index(3);
+        let rotation = random_rotation(&mut rng, 30);
+        free.rotate(wheel, rotation);
+        alone[wheel].rotate(rotation);
+    }
+    assert_eq!(free.wheels(), &alone[..]);
+}
+
+#[test]
+fn reverse_carry_turns_the_next_wheel_back() {
+    let mut gears = lock(2, 10, Carry::Reverse(3));
+    gears.rotate(0, Rotation::new(Direction::Right, 25).unwrap());
+    assert_eq!(gears.wheels()[0].zero_count, 2);
+    assert_eq!(gears.wheels()[1].state(), 4);
+    assert_eq!(gears.wheels()[1].zero_count, 0);
+}
+
+#[test]
+fn rotations_are_addressed_to_wheels() {
+    let rotations = parse_wheel_rotations("2:L5 R3, # first wheel\n3:+7\n", 3).unwrap();
+    let expected = [
+        (1, Rotation::new(Direction::Left, 5).unwrap()),
+        (0, Rotation::new(Direction::Right, 3).unwrap()),
+        (2, Rotation::new(Direction::Right, 7).unwrap()),
+    ]
+    .map(|(wheel, rotation)| WheelRotation { wheel, rotation });
+    assert_eq!(rotations, expected);
+    assert_eq!(rotations[0].to_string(), "2:L5");
+}
+
+#[test]
+fn bad_wheels_are_reported_where_they_are() {
+    let error = |input: &str| {
+        let err = parse_wheel_rotations(input, 3).unwrap_err();
+        (err.line, err.column, err.kind::<LockError>().cloned())
+    };
+    let no_such_wheel = LockError::NoSuchWheel {
+        wheel: 4,
+        wheels: 3,
+    };
+    assert_eq!(error("R1\n 4:R1"), (2, 2, Some(no_such_wheel)));
+    assert_eq!(error("0:R1"), (1, 1, Some(LockError::WheelZero)));
+    assert!(matches!(
+        error("x:R1"),
+        (1, 1, Some(LockError::InvalidWheel(_)))
+    ));
+    // Not a wheel error: the rotation after the wheel is bad.
+    assert_eq!(error("R1 2:Q1"), (1, 6, None));
+}
+
+#[test]
+fn carries_parse_with_optional_clicks() {
+    assert_eq!("free".parse(), Ok(Carry::Free));
+    assert_eq!("forward".parse(), Ok(Carry::Forward(1)));
+    assert_eq!("reverse:3".parse(), Ok(Carry::Reverse(3)));
+    for bad in ["free:1", "free:2", "forward:-1", "reverse:", "sideways"] {
+        assert_eq!(
+            bad.parse::<Carry>(),
+            Err(LockError::UnknownCarry(bad.to_owned()))
+        );
+    }
+}
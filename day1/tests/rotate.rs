@@ -0,0 +1,98 @@
+//! Compares the closed form in `Dial::rotate` with the click by click
+//! reference on random dials and rotations.
+
+mod common;
+
+use aoc_common::Rng;
+use common::random_rotation;
+use day1::{Dial, Direction, Rotation};
+
+const CASES: usize = 20_000;
+
+/// Turns copies of `dial` both ways, failing with the case if they disagree.
+fn agree(dial: Dial, rotation: Rotation) {
+    let (mut fast, mut slow) = (dial, dial);
+    let crossings = fast.rotate(rotation);
+    let expected = slow.rotate_clicks(rotation);
+    assert_eq!(
+        (crossings, fast),
+        (expected, slow),
+        "{:?} turned by {:?}",
+        dial,
+        rotation
+    );
+}
+
+#[test]
+fn single_rotations_match_the_reference() {
+    let mut rng = Rng::new(1);
+    for _ in 0..CASES {
+        let modulus = rng.range(1..=120) as i32;
+        let start = rng.below(modulus as u64) as i32;
+        let dial = Dial::new(modulus, start).unwrap();
+        // Up to five whole turns, so rotations often wrap several times.
+        let rotation = random_rotation(&mut rng, 5 * modulus as u64 + 2);
+        agree(dial, rotation);
+    }
+}
+
+#[test]
+fn edge_rotations_match_the_reference() {
+    for modulus in 1..=12 {
+        for start in 0..modulus {
+            let dial = Dial::new(modulus, start).unwrap();
+            for direction in [Direction::Left, Direction::Right] {
+                let amounts = [
+                    0,
+                    1,
+                    start,
+                    start + 1,
+                    modulus - start,
+                    modulus,
+                    3 * modulus,
+                ];
+                for amount in amounts {
+                    agree(dial, Rotation::new(direction, i64::from(amount)).unwrap());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn rotation_sequences_match_the_reference() {
+    let mut rng = Rng::new(2);
+    for _ in 0..CASES / 100 {
+        let modulus = rng.range(1..=50) as i32;
+        let start = rng.below(modulus as u64) as i32;
+        let (mut fast, mut slow) = (
+            Dial::new(modulus, start).unwrap(),
+            Dial::new(modulus, start).unwrap(),
+        );
+        for _ in 0..100 {
+            let rotation = random_rotation(&mut rng, 3 * modulus as u64);
+            fast.rotate(rotation);
+            slow.rotate_clicks(rotation);
+        }
+        assert_eq!(
+            fast, slow,
+            "dials of {} positions from {} drifted apart",
+            modulus, start
+        );
+    }
+}
+
+#[test]
+fn dials_stay_on_their_positions() {
+    assert!(Dial::new(0, 0).is_err());
+    assert!(Dial::new(10, 10).is_err());
+    assert!(Dial::new(10, -1).is_err());
+
+    let mut dial = Dial::new(7, 3).unwrap();
+    for amount in [0, 4, 11, 700, i64::MAX] {
+        for direction in [Direction::Left, Direction::Right] {
+            dial.rotate(Rotation::new(direction, amount).unwrap());
+            assert!((0..dial.modulus()).contains(&dial.state()));
+        }
+    }
+}
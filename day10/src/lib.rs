@@ -0,0 +1,708 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use aoc_common::{
+    Answer, Error, Generate, Line, ParseError, Rng, Solution, Streaming,
+    runner::{Day, Flags},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MachineError {
+    MissingLights,
+    UnknownLight(char),
+    MissingJoltages,
+    UnexpectedToken,
+    InvalidNumber(ParseIntError),
+    ButtonOutOfRange { index: usize, counters: usize },
+    LightCountMismatch { lights: usize, counters: usize },
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineError::MissingLights => write!(f, "expected a `[...]` light diagram"),
+            MachineError::UnknownLight(c) => {
+                write!(f, "unknown light `{}`, expected `.` or `#`", c)
+            }
+            MachineError::MissingJoltages => {
+                write!(f, "expected `{{...}}` joltage requirements")
+            }
+            MachineError::UnexpectedToken => write!(f, "expected a `(...)` button"),
+            MachineError::InvalidNumber(err) => write!(f, "invalid number: {}", err),
+            MachineError::ButtonOutOfRange { index, counters } => write!(
+                f,
+                "button wires counter {} but there are only {}",
+                index, counters
+            ),
+            MachineError::LightCountMismatch { lights, counters } => write!(
+                f,
+                "diagram has {} lights but there are {} joltage counters",
+                lights, counters
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MachineError {}
+
+#[derive(Debug)]
+pub struct Machine {
+    lights: Vec<bool>,
+    buttons: Vec<Vec<f64>>,
+    target: Vec<f64>,
+    num_counters: usize,
+    num_buttons: usize,
+}
+
+/// Byte offset of `part`, a subslice of `s`, within `s`.
+fn offset_in(s: &str, part: &str) -> usize {
+    part.as_ptr() as usize - s.as_ptr() as usize
+}
+
+/// Finds the `open`...`close` group, returning the offsets of both delimiters.
+fn find_group(
+    s: &str,
+    open: char,
+    close: char,
+    missing: MachineError,
+) -> Result<(usize, usize), ParseError> {
+    let start = s
+        .find(open)
+        .ok_or_else(|| ParseError::at(1, s, missing.clone()))?;
+    let end = s[start..]
+        .find(close)
+        .map(|i| start + i)
+        .ok_or_else(|| ParseError::at(start + 1, &s[start..], missing))?;
+    Ok((start, end))
+}
+
+/// Parses the comma separated numbers of a group, `list` being a subslice of `s`.
+fn parse_numbers<T: FromStr<Err = ParseIntError>>(
+    s: &str,
+    list: &str,
+) -> Result<Vec<T>, ParseError> {
+    list.split(',')
+        .map(|n| {
+            let token = n.trim();
+            token.parse().map_err(|err| {
+                ParseError::at(
+                    offset_in(s, token) + 1,
+                    token,
+                    MachineError::InvalidNumber(err),
+                )
+            })
+        })
+        .collect()
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start_brace, end_brace) = find_group(s, '{', '}', MachineError::MissingJoltages)?;
+        let target: Vec<f64> = parse_numbers::<u32>(s, &s[start_brace + 1..end_brace])?
+            .into_iter()
+            .map(f64::from)
+            .collect();
+        let num_counters = target.len();
+
+        let (start_lights, end_lights) =
+            find_group(&s[..start_brace], '[', ']', MachineError::MissingLights)?;
+        let lights = s[start_lights + 1..end_lights]
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(false),
+                '#' => Ok(true),
+                _ => Err(ParseError::at(
+                    start_lights + i + 2,
+                    c,
+                    MachineError::UnknownLight(c),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if lights.len() != num_counters {
+            return Err(ParseError::at(
+                start_lights + 1,
+                &s[start_lights..=end_lights],
+                MachineError::LightCountMismatch {
+                    lights: lights.len(),
+                    counters: num_counters,
+                },
+            ));
+        }
+
+        let button_section = &s[end_lights + 1..start_brace];
+        let mut buttons = Vec::new();
+        for token in button_section.split_whitespace() {
+            let column = offset_in(s, token) + 1;
+            let wiring = token
+                .strip_prefix('(')
+                .and_then(|t| t.strip_suffix(')'))
+                .ok_or_else(|| ParseError::at(column, token, MachineError::UnexpectedToken))?;
+
+            let mut vec = vec![0.0; num_counters];
+            for idx in parse_numbers::<usize>(s, wiring)? {
+                if idx >= num_counters {
+                    return Err(ParseError::at(
+                        column,
+                        token,
+                        MachineError::ButtonOutOfRange {
+                            index: idx,
+                            counters: num_counters,
+                        },
+                    ));
+                }
+                vec[idx] = 1.0;
+            }
+            buttons.push(vec);
+        }
+        let len = buttons.len();
+        Ok(Machine {
+            lights,
+            buttons,
+            target,
+            num_counters,
+            num_buttons: len,
+        })
+    }
+}
+
+/// The most steps `configure_lights` takes searching the choices left once
+/// elimination is done.
+pub const MAX_LIGHT_SEARCH: u64 = 1 << 24;
+
+/// Steps to try `2^bits` choices at `each` steps apiece, if that fits.
+fn search_steps(bits: usize, each: usize) -> Option<u64> {
+    let choices = 1_u64.checked_shl(u32::try_from(bits).ok()?)?;
+    choices.checked_mul(each.max(1) as u64)
+}
+
+/// A row of bits, as many words long as needed.
+#[derive(Debug, Clone)]
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Bits(vec![0; len.div_ceil(64)])
+    }
+
+    fn get(&self, i: usize) -> bool {
+        self.0[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    fn set(&mut self, i: usize) {
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    fn xor(&mut self, other: &Bits) {
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word ^= other;
+        }
+    }
+}
+
+impl Machine {
+    /// Fewest presses that leave exactly the lights of the diagram on, or
+    /// `None` if no presses do.
+    ///
+    /// Pressing a button twice cancels out, so this is a linear system over
+    /// GF(2), one equation per light. Gaussian elimination leaves every
+    /// pivot button fixed by the free ones, so either the `2^free` choices
+    /// of free buttons are tried, or a breadth first search runs over the
+    /// `2^rank` states of the independent lights, whichever is smaller.
+    /// Fails with [`Error::NoSolution`] when both take more than
+    /// [`MAX_LIGHT_SEARCH`] steps.
+    pub fn configure_lights(&self) -> Result<Option<usize>, Error> {
+        let buttons = self.num_buttons;
+        // One row per light: the buttons toggling it, then whether it is on.
+        let mut rows: Vec<Bits> = self
+            .lights
+            .iter()
+            .enumerate()
+            .map(|(light, &on)| {
+                let mut row = Bits::new(buttons + 1);
+                for (b, button) in self.buttons.iter().enumerate() {
+                    if button[light] > 0.5 {
+                        row.set(b);
+                    }
+                }
+                if on {
+                    row.set(buttons);
+                }
+                row
+            })
+            .collect();
+
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+        for b in 0..buttons {
+            let Some(found) = (pivots.len()..rows.len()).find(|&r| rows[r].get(b)) else {
+                free.push(b);
+                continue;
+            };
+            rows.swap(pivots.len(), found);
+            let pivot = rows[pivots.len()].clone();
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r != pivots.len() && row.get(b) {
+                    row.xor(&pivot);
+                }
+            }
+            pivots.push(b);
+        }
+        // Rows past the pivots have no buttons left, so must have no light.
+        if rows[pivots.len()..].iter().any(|row| row.get(buttons)) {
+            return Ok(None);
+        }
+        let rows = &rows[..pivots.len()];
+
+        let by_free = search_steps(free.len(), rows.len());
+        let by_lights = search_steps(rows.len(), buttons);
+        match (by_free, by_lights) {
+            (Some(steps), _)
+                if steps <= MAX_LIGHT_SEARCH && by_lights.is_none_or(|l| steps <= l) =>
+            {
+                Ok(Some(fewest_by_free(rows, &free, buttons)))
+            }
+            (_, Some(steps)) if steps <= MAX_LIGHT_SEARCH => {
+                Ok(Some(fewest_by_lights(rows, buttons)))
+            }
+            _ => Err(Error::NoSolution(format!(
+                "{} free buttons and {} independent lights are too many to search",
+                free.len(),
+                rows.len()
+            ))),
+        }
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    pub fn solve(&self) -> Option<usize> {
+        // Build Augmented Matrix
+        let mut m = vec![vec![0.0; self.num_buttons + 1]; self.num_counters];
+        for r in 0..self.num_counters {
+            for c in 0..self.num_buttons {
+                m[r][c] = self.buttons[c][r];
+            }
+            m[r][self.num_buttons] = self.target[r];
+        }
+
+        // Gaussian Elimination
+        let mut pivot_row = 0;
+        let mut col_to_pivot_row = vec![None; self.num_buttons];
+        let mut free_vars = Vec::new();
+
+        for c in 0..self.num_buttons {
+            if pivot_row >= self.num_counters {
+                free_vars.push(c);
+                continue;
+            }
+
+            let mut best_r = None;
+            for r in pivot_row..self.num_counters {
+                if m[r][c].abs() > 1e-5 {
+                    best_r = Some(r);
+                    break;
+                }
+            }
+
+            if let Some(r) = best_r {
+                m.swap(pivot_row, r);
+                let pivot_val = m[pivot_row][c];
+                // Normalize row
+                for k in c..=self.num_buttons {
+                    m[pivot_row][k] /= pivot_val;
+                }
+                // Eliminate column
+                for other_r in 0..self.num_counters {
+                    if other_r != pivot_row {
+                        let factor = m[other_r][c];
+                        if factor.abs() > 1e-9 {
+                            for k in c..=self.num_buttons {
+                                m[other_r][k] -= factor * m[pivot_row][k];
+                            }
+                        }
+                    }
+                }
+                col_to_pivot_row[c] = Some(pivot_row);
+                pivot_row += 1;
+            } else {
+                free_vars.push(c);
+            }
+        }
+
+        for r in pivot_row..self.num_counters {
+            if m[r][self.num_buttons].abs() > 1e-4 {
+                return None;
+            }
+        }
+
+        if free_vars.is_empty() {
+            let mut total = 0;
+            for c in 0..self.num_buttons {
+                if let Some(r) = col_to_pivot_row[c] {
+                    let val = m[r][self.num_buttons];
+                    if val < -1e-4 {
+                        return None;
+                    }
+                    let round = val.round();
+                    if (val - round).abs() > 1e-4 {
+                        return None;
+                    }
+                    total += round as usize;
+                }
+            }
+            return Some(total);
+        }
+
+        // Search for minimum solution using branch and bound
+        let mut best_solution = None;
+
+        fn search_min(
+            machine: &Machine,
+            idx: usize,
+            free_vars: &[usize],
+            free_vals: &mut [usize],
+            m: &Vec<Vec<f64>>,
+            col_to_pivot_row: &Vec<Option<usize>>,
+            best_so_far: &mut Option<usize>,
+        ) {
+            // Only sum the free variables we've set so far (0..idx)
+            let current_sum: usize = free_vals[0..idx].iter().sum();
+
+            // Prune if we already exceeded the best solution
+            if let Some(best) = *best_so_far
+                && current_sum >= best
+            {
+                return;
+            }
+
+            if idx == free_vars.len() {
+                let total_free: usize = free_vals.iter().sum();
+                let mut total = total_free;
+
+                for c in 0..machine.num_buttons {
+                    if let Some(r) = col_to_pivot_row[c] {
+                        let mut val = m[r][machine.num_buttons];
+                        for (i, &fv) in free_vars.iter().enumerate() {
+                            val -= m[r][fv] * (free_vals[i] as f64);
+                        }
+
+                        if val < -1e-4 {
+                            return;
+                        }
+                        let round = val.round();
+                        if (val - round).abs() > 1e-4 {
+                            return;
+                        }
+                        total += round as usize;
+                    }
+                }
+
+                if best_so_far.is_none() || total < best_so_far.unwrap() {
+                    *best_so_far = Some(total);
+                }
+                return;
+            }
+
+            let max_val = machine.target.iter().map(|&x| x as usize).sum::<usize>();
+
+            for val in 0..=max_val {
+                free_vals[idx] = val;
+
+                let mut valid = true;
+                for c in 0..machine.num_buttons {
+                    if let Some(r) = col_to_pivot_row[c] {
+                        let mut depends_on_future = false;
+                        for future_idx in (idx + 1)..free_vars.len() {
+                            if m[r][free_vars[future_idx]].abs() > 1e-9 {
+                                depends_on_future = true;
+                                break;
+                            }
+                        }
+
+                        if !depends_on_future {
+                            let mut val = m[r][machine.num_buttons];
+                            for i in 0..=idx {
+                                let fv = free_vars[i];
+                                val -= m[r][fv] * (free_vals[i] as f64);
+                            }
+
+                            if val < -1e-4 {
+                                valid = false;
+                                break;
+                            }
+                            let round = val.round();
+                            if (val - round).abs() > 1e-4 {
+                                valid = false;
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                if valid {
+                    search_min(
+                        machine,
+                        idx + 1,
+                        free_vars,
+                        free_vals,
+                        m,
+                        col_to_pivot_row,
+                        best_so_far,
+                    );
+                }
+            }
+        }
+
+        let mut free_vals = vec![0; free_vars.len()];
+        search_min(
+            self,
+            0,
+            &free_vars,
+            &mut free_vals,
+            &m,
+            &col_to_pivot_row,
+            &mut best_solution,
+        );
+
+        best_solution
+    }
+}
+
+/// Tries every choice of the `free` buttons, given the reduced pivot `rows`
+/// whose last column, `buttons`, is the light.
+fn fewest_by_free(rows: &[Bits], free: &[usize], buttons: usize) -> usize {
+    // Each pivot button is its light bit plus the free buttons in its row.
+    let equations: Vec<(u64, bool)> = rows
+        .iter()
+        .map(|row| {
+            let mask = (0..free.len())
+                .filter(|&i| row.get(free[i]))
+                .fold(0, |mask, i| mask | 1 << i);
+            (mask, row.get(buttons))
+        })
+        .collect();
+    (0..1_u64 << free.len())
+        .map(|choice| {
+            let pressed = equations
+                .iter()
+                .filter(|&&(mask, on)| ((mask & choice).count_ones() % 2 == 1) != on)
+                .count();
+            choice.count_ones() as usize + pressed
+        })
+        .min()
+        .expect("there is at least the empty choice")
+}
+
+/// Searches outward from all lights off, one press at a time, for the lights
+/// of the reduced pivot `rows`, whose last column, `buttons`, is the light.
+///
+/// Every light state the search reaches is some set of presses, so the first
+/// time it reaches the diagram is with the fewest.
+fn fewest_by_lights(rows: &[Bits], buttons: usize) -> usize {
+    let column = |b: usize| {
+        (0..rows.len())
+            .filter(|&r| rows[r].get(b))
+            .fold(0_usize, |state, r| state | 1 << r)
+    };
+    let target = column(buttons);
+    let mut toggles: Vec<usize> = (0..buttons).map(column).filter(|&t| t != 0).collect();
+    toggles.sort_unstable();
+    toggles.dedup();
+
+    // The fewest presses never exceed the pivot buttons, so fit in a byte.
+    let mut presses: Vec<Option<u8>> = vec![None; 1 << rows.len()];
+    presses[0] = Some(0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(state) = queue.pop_front() {
+        let pressed = presses[state].expect("queued states are reached");
+        if state == target {
+            return pressed.into();
+        }
+        for &toggle in &toggles {
+            let next = state ^ toggle;
+            if presses[next].is_none() {
+                presses[next] = Some(pressed + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    unreachable!("the pivot buttons alone reach every light state")
+}
+
+/// Fewest presses for the lights of machine `number`, counted from 1.
+fn light_presses(machine: &Machine, number: usize) -> Result<usize, Error> {
+    match machine.configure_lights() {
+        Ok(Some(presses)) => Ok(presses),
+        Ok(None) => Err(Error::NoSolution(format!(
+            "machine {} cannot light its diagram",
+            number
+        ))),
+        Err(Error::NoSolution(why)) => {
+            Err(Error::NoSolution(format!("machine {}: {}", number, why)))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Fewest presses for the joltages of machine `number`, counted from 1.
+fn joltage_presses(machine: &Machine, number: usize) -> Result<usize, Error> {
+    machine
+        .solve()
+        .ok_or_else(|| Error::NoSolution(format!("machine {} is unsolvable", number)))
+}
+
+/// Running button press totals of both parts.
+#[derive(Debug, Default)]
+pub struct Presses {
+    machines: usize,
+    totals: [usize; 2],
+    /// Why each part failed, for the first machine it cannot solve.
+    failures: [Option<String>; 2],
+}
+
+impl Presses {
+    /// Adds the presses `part` needs for `machine`, the latest one.
+    fn add(&mut self, machine: &Machine, part: u8) {
+        let i = usize::from(part - 1);
+        let presses = match part {
+            1 => light_presses(machine, self.machines),
+            _ => joltage_presses(machine, self.machines),
+        };
+        match presses {
+            Ok(presses) => self.totals[i] += presses,
+            Err(err) => {
+                self.failures[i].get_or_insert(match err {
+                    Error::NoSolution(why) => why,
+                    err => err.to_string(),
+                });
+            }
+        }
+    }
+
+    fn total(&self, part: u8) -> Result<Answer, Error> {
+        let i = usize::from(part - 1);
+        match &self.failures[i] {
+            Some(why) => Err(Error::NoSolution(why.clone())),
+            None => Ok(self.totals[i].into()),
+        }
+    }
+}
+
+impl Solution for Machine {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        aoc_common::parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        let mut total = 0;
+        for (i, machine) in input.iter().enumerate() {
+            total += light_presses(machine, i + 1)?;
+        }
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        let mut total = 0;
+        for (i, machine) in input.iter().enumerate() {
+            total += joltage_presses(machine, i + 1)?;
+        }
+        Ok(total.into())
+    }
+}
+
+/// Each part asked for is solved for each machine as it is read.
+impl Streaming for Machine {
+    type State = Presses;
+
+    type Item = Machine;
+
+    fn start(_flags: &mut Flags) -> Result<Self::State, Error> {
+        Ok(Presses::default())
+    }
+
+    fn feed(state: &mut Self::State, line: Line<'_>) -> Result<Self::Item, ParseError> {
+        let machine = line.parse()?;
+        state.machines += 1;
+        Ok(machine)
+    }
+
+    fn fold(state: &mut Self::State, machine: &Self::Item, part: u8) {
+        state.add(machine, part);
+    }
+
+    fn answer(state: &Self::State, part: u8) -> Result<Answer, Error> {
+        state.total(part)
+    }
+}
+
+/// Writes `size` machines whose buttons each have a counter of their own that
+/// no other button touches. That pins down which buttons the lights need and
+/// how often every button is pressed for the joltages, so both answers are
+/// known by construction.
+impl Generate for Machine {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        let mut lights_total = 0;
+        let mut presses_total = 0;
+
+        for _ in 0..size {
+            let buttons = rng.range(3..=8) as usize;
+            let counters = buttons + rng.range(2..=4) as usize;
+            let mut order: Vec<usize> = (0..counters).collect();
+            rng.shuffle(&mut order);
+            let (own, shared) = order.split_at(buttons);
+
+            let wiring: Vec<Vec<usize>> = own
+                .iter()
+                .map(|&counter| {
+                    let mut wires = vec![counter];
+                    wires.extend(shared.iter().filter(|_| rng.chance(0.5)));
+                    wires.sort_unstable();
+                    wires
+                })
+                .collect();
+
+            let mut lights = vec![false; counters];
+            let mut joltages = vec![0; counters];
+            for wires in &wiring {
+                let toggled = rng.chance(0.5);
+                let presses = rng.range(0..=30);
+                lights_total += usize::from(toggled);
+                presses_total += presses;
+                for &counter in wires {
+                    lights[counter] ^= toggled;
+                    joltages[counter] += presses;
+                }
+            }
+
+            let diagram: String = lights
+                .iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect();
+            write!(out, "[{}]", diagram)?;
+            for wires in &wiring {
+                let wires: Vec<String> = wires.iter().map(usize::to_string).collect();
+                write!(out, " ({})", wires.join(","))?;
+            }
+            let joltages: Vec<String> = joltages.iter().map(u64::to_string).collect();
+            writeln!(out, " {{{}}}", joltages.join(","))?;
+        }
+
+        Ok(vec![(1, lights_total.into()), (2, presses_total.into())])
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day10` binary.
+pub fn day() -> Day {
+    Day::streaming::<Machine>().generating::<Machine>()
+}
@@ -0,0 +1,125 @@
+//! Checks the GF(2) light solver against trying every set of buttons.
+
+use aoc_common::Error;
+use aoc_common::Rng;
+use day10::Machine;
+
+/// Writes a machine line for `lights` and `buttons`, with every joltage 1.
+fn machine(lights: &[bool], buttons: &[Vec<usize>]) -> String {
+    let diagram: String = lights
+        .iter()
+        .map(|&on| if on { '#' } else { '.' })
+        .collect();
+    let buttons: Vec<String> = buttons
+        .iter()
+        .map(|wires| {
+            let wires: Vec<String> = wires.iter().map(usize::to_string).collect();
+            format!("({})", wires.join(","))
+        })
+        .collect();
+    let joltages = vec!["1"; lights.len()].join(",");
+    format!("[{}] {} {{{}}}", diagram, buttons.join(" "), joltages)
+}
+
+fn fewest_presses(lights: &[bool], buttons: &[Vec<usize>]) -> Option<usize> {
+    (0_u32..1 << buttons.len())
+        .filter(|subset| {
+            let mut lit = vec![false; lights.len()];
+            for (b, wires) in buttons.iter().enumerate() {
+                if subset >> b & 1 == 1 {
+                    for &light in wires {
+                        lit[light] ^= true;
+                    }
+                }
+            }
+            lit == lights
+        })
+        .map(|subset| subset.count_ones() as usize)
+        .min()
+}
+
+#[test]
+fn elimination_matches_trying_every_subset() {
+    let mut rng = Rng::new(10);
+    for _ in 0..500 {
+        let count = rng.range(1..=8) as usize;
+        let lights: Vec<bool> = (0..count).map(|_| rng.chance(0.5)).collect();
+        let buttons: Vec<Vec<usize>> = (0..rng.range(1..=10))
+            .map(|_| {
+                let mut wires: Vec<usize> = (0..count).filter(|_| rng.chance(0.4)).collect();
+                if wires.is_empty() {
+                    wires.push(rng.index(count));
+                }
+                wires
+            })
+            .collect();
+        let line = machine(&lights, &buttons);
+        let parsed: Machine = line.parse().unwrap();
+        assert_eq!(
+            parsed.configure_lights().unwrap(),
+            fewest_presses(&lights, &buttons),
+            "{}",
+            line
+        );
+    }
+}
+
+#[test]
+fn machines_may_have_many_lights_and_buttons() {
+    // Button `b` toggles lights `b` and `b + 1`, so lighting just the first
+    // and last light takes every button.
+    for buttons in [64, 100] {
+        let lights: Vec<bool> = (0..=buttons).map(|i| i == 0 || i == buttons).collect();
+        let wiring: Vec<Vec<usize>> = (0..buttons).map(|b| vec![b, b + 1]).collect();
+        let parsed: Machine = machine(&lights, &wiring).parse().unwrap();
+        assert_eq!(parsed.configure_lights().unwrap(), Some(buttons));
+    }
+}
+
+#[test]
+fn many_free_buttons_search_the_lights_instead() {
+    let parsed: Machine = machine(&[true, true], &vec![vec![0, 1]; 30])
+        .parse()
+        .unwrap();
+    assert_eq!(parsed.configure_lights().unwrap(), Some(1));
+    let parsed: Machine = machine(&[true], &vec![vec![0]; 40]).parse().unwrap();
+    assert_eq!(parsed.configure_lights().unwrap(), Some(1));
+
+    // Whichever way round, a few lights and many buttons agree with brute
+    // force.
+    let mut rng = Rng::new(11);
+    for _ in 0..50 {
+        let count = rng.range(1..=4) as usize;
+        let lights: Vec<bool> = (0..count).map(|_| rng.chance(0.5)).collect();
+        let buttons: Vec<Vec<usize>> = (0..16)
+            .map(|_| {
+                let mut wires: Vec<usize> = (0..count).filter(|_| rng.chance(0.5)).collect();
+                if wires.is_empty() {
+                    wires.push(rng.index(count));
+                }
+                wires
+            })
+            .collect();
+        let parsed: Machine = machine(&lights, &buttons).parse().unwrap();
+        assert_eq!(
+            parsed.configure_lights().unwrap(),
+            fewest_presses(&lights, &buttons)
+        );
+    }
+}
+
+#[test]
+fn searches_too_big_either_way_are_refused() {
+    // Forty independent lights, each with its own button, and forty more
+    // buttons toggling pairs of them leave forty free buttons.
+    let lights = vec![true; 40];
+    let buttons: Vec<Vec<usize>> = (0..40)
+        .map(|b| vec![b])
+        .chain((0..40).map(|b| vec![b, (b + 1) % 40]))
+        .collect();
+    let parsed: Machine = machine(&lights, &buttons).parse().unwrap();
+    assert!(matches!(
+        parsed.configure_lights(),
+        Err(Error::NoSolution(_))
+    ));
+}
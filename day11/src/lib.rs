@@ -0,0 +1,195 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, io,
+    str::FromStr,
+};
+
+use aoc_common::{Answer, Error, Generate, ParseError, Rng, Solution, runner::Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    MissingColon,
+    MissingName,
+    DuplicateDevice(String),
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::MissingColon => write!(f, "line must look like `device: outputs...`"),
+            NetworkError::MissingName => write!(f, "device has no name"),
+            NetworkError::DuplicateDevice(name) => {
+                write!(f, "device `{}` is listed more than once", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+pub struct ReactorNetwork {
+    adj: HashMap<String, Vec<String>>,
+}
+
+impl FromStr for ReactorNetwork {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut adj = HashMap::new();
+
+        for line in aoc_common::lines(s) {
+            let error = |kind| ParseError::new(line.number, line.column, line.text, kind);
+
+            let (src, dests_str) = line
+                .text
+                .split_once(':')
+                .ok_or_else(|| error(NetworkError::MissingColon))?;
+            let src = src.trim().to_string();
+            if src.is_empty() {
+                return Err(error(NetworkError::MissingName));
+            }
+            let dests: Vec<String> = dests_str
+                .split_whitespace()
+                .map(|s| s.to_string())
+                .collect();
+
+            if adj.contains_key(&src) {
+                return Err(error(NetworkError::DuplicateDevice(src)));
+            }
+            adj.insert(src, dests);
+        }
+        Ok(ReactorNetwork { adj })
+    }
+}
+
+impl ReactorNetwork {
+    pub fn count_paths(&self, start: &str, end: &str) -> u64 {
+        if !self.adj.contains_key(start) {
+            aoc_common::warn(format!("device `{}` is not in the network", start));
+        }
+        let mut memo = HashMap::new();
+        self.dfs(start, end, &mut memo)
+    }
+
+    fn dfs(&self, current: &str, target: &str, memo: &mut HashMap<String, u64>) -> u64 {
+        if let Some(&count) = memo.get(current) {
+            return count;
+        }
+
+        if current == target {
+            return 1;
+        }
+
+        let mut total_paths = 0;
+
+        if let Some(neighbors) = self.adj.get(current) {
+            for neighbor in neighbors {
+                total_paths += self.dfs(neighbor, target, memo);
+            }
+        }
+
+        memo.insert(current.to_string(), total_paths);
+        total_paths
+    }
+
+    pub fn solve_visits(&self) -> u64 {
+        // Scenario 1: svr -> dac -> fft -> out
+        let svr_dac = self.count_paths("svr", "dac");
+        let dac_fft = self.count_paths("dac", "fft");
+        let fft_out = self.count_paths("fft", "out");
+
+        let path1_count = svr_dac * dac_fft * fft_out;
+
+        // Scenario 2: svr -> fft -> dac -> out
+        let svr_fft = self.count_paths("svr", "fft");
+        let fft_dac = self.count_paths("fft", "dac");
+        let dac_out = self.count_paths("dac", "out");
+        let path2_count = svr_fft * fft_dac * dac_out;
+
+        path1_count + path2_count
+    }
+}
+
+impl Solution for ReactorNetwork {
+    const DAY: u8 = 11;
+
+    type Input = ReactorNetwork;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.count_paths("you", "out").into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.solve_visits().into())
+    }
+}
+
+/// Devices the puzzle asks about, kept out of the randomly named ones.
+const RESERVED: [&str; 5] = ["you", "out", "svr", "dac", "fft"];
+
+/// Writes a network of about `size` devices in a dozen layers, each device
+/// feeding one to three devices of the next layer and the last layer feeding
+/// `out`. The layers keep the path counts well within range.
+impl Generate for ReactorNetwork {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        const LAYERS: usize = 12;
+
+        let mut name_len = 3;
+        while 26_usize.pow(name_len) < 2 * size {
+            name_len += 1;
+        }
+        let mut names: HashSet<String> = RESERVED.iter().map(|name| name.to_string()).collect();
+
+        let mut layers: Vec<Vec<String>> = vec![Vec::new(); LAYERS];
+        for (name, layer) in [("svr", 0), ("you", 1), ("dac", 4), ("fft", 8)] {
+            layers[layer].push(name.to_owned());
+        }
+        for _ in RESERVED.len()..size {
+            let name = loop {
+                let name: String = (0..name_len)
+                    .map(|_| char::from(b'a' + rng.below(26) as u8))
+                    .collect();
+                if names.insert(name.clone()) {
+                    break name;
+                }
+            };
+            layers[rng.index(LAYERS)].push(name);
+        }
+        layers.push(vec!["out".to_owned()]);
+
+        let mut lines = Vec::new();
+        for (i, layer) in layers.iter().enumerate().take(LAYERS) {
+            let next = layers[i + 1..]
+                .iter()
+                .find(|layer| !layer.is_empty())
+                .expect("`out` is always last");
+            for device in layer {
+                let mut outputs: Vec<&str> = (0..rng.range(1..=3))
+                    .map(|_| next[rng.index(next.len())].as_str())
+                    .collect();
+                outputs.sort_unstable();
+                outputs.dedup();
+                lines.push(format!("{}: {}", device, outputs.join(" ")));
+            }
+        }
+
+        rng.shuffle(&mut lines);
+        for line in lines {
+            writeln!(out, "{}", line)?;
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day11` binary.
+pub fn day() -> Day {
+    Day::of::<ReactorNetwork>().generating::<ReactorNetwork>()
+}
@@ -0,0 +1,407 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+
+use aoc_common::{Answer, Error, Generate, Grid, Line, ParseError, Rng, Solution, runner::Day};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Shape {
+    // Coordinates relative to top-left (0,0)
+    points: Vec<(usize, usize)>,
+    width: usize,
+    height: usize,
+}
+
+impl Shape {
+    fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    fn normalize(&self) -> Shape {
+        if self.points.is_empty() {
+            return self.clone();
+        }
+        let min_r = self.points.iter().map(|p| p.0).min().unwrap();
+        let min_c = self.points.iter().map(|p| p.1).min().unwrap();
+
+        let new_points: Vec<_> = self
+            .points
+            .iter()
+            .map(|(r, c)| (r - min_r, c - min_c))
+            .collect();
+
+        let height = new_points.iter().map(|p| p.0).max().unwrap() + 1;
+        let width = new_points.iter().map(|p| p.1).max().unwrap() + 1;
+
+        // Sort points to ensure canonical representation for deduplication
+        let mut sorted_points = new_points;
+        sorted_points.sort();
+
+        Shape {
+            points: sorted_points,
+            width,
+            height,
+        }
+    }
+
+    /// Generate unique variations (rotations + flips)
+    fn generate_variants(&self) -> Vec<Shape> {
+        let mut unique = HashSet::new();
+        let mut variants = Vec::new();
+
+        let mut current = self.clone();
+
+        for _ in 0..4 {
+            let norm = current.normalize();
+            if unique.insert(norm.clone()) {
+                variants.push(norm);
+            }
+
+            let mut flipped = current.clone();
+            flipped.points = flipped
+                .points
+                .iter()
+                .map(|&(r, c)| (r, current.width - 1 - c))
+                .collect();
+            let norm_flip = flipped.normalize();
+            if unique.insert(norm_flip.clone()) {
+                variants.push(norm_flip);
+            }
+
+            // Rotate 90 deg clockwise
+            let new_points: Vec<_> = current
+                .points
+                .iter()
+                .map(|&(r, c)| (c, current.height - 1 - r))
+                .collect();
+            current = Shape {
+                points: new_points,
+                width: current.height,
+                height: current.width,
+            }
+            .normalize();
+        }
+
+        variants
+    }
+}
+
+/// Whether `shape` fits in the free cells of `region` with its top left at row
+/// `r`, column `c`.
+fn can_fit(region: &Grid<bool>, r: usize, c: usize, shape: &Shape) -> bool {
+    if r + shape.height > region.height() || c + shape.width > region.width() {
+        return false;
+    }
+    shape
+        .points
+        .iter()
+        .all(|&(pr, pc)| !region[(c + pc, r + pr)])
+}
+
+/// Places `shape` at row `r`, column `c`, or takes it away again.
+fn toggle(region: &mut Grid<bool>, r: usize, c: usize, shape: &Shape) {
+    for &(pr, pc) in &shape.points {
+        let cell = &mut region[(c + pc, r + pr)];
+        *cell = !*cell;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FarmError {
+    UnexpectedLine,
+    ShapeOutOfOrder { expected: usize, found: usize },
+    UnknownCell(char),
+    MissingDimensions,
+    InvalidNumber(ParseIntError),
+    UnknownShape(usize),
+}
+
+impl fmt::Display for FarmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FarmError::UnexpectedLine => {
+                write!(f, "expected a `N:` shape or a `WxH: counts...` region")
+            }
+            FarmError::ShapeOutOfOrder { expected, found } => {
+                write!(
+                    f,
+                    "shape {} is out of order, expected shape {}",
+                    found, expected
+                )
+            }
+            FarmError::UnknownCell(c) => write!(f, "unknown cell `{}`, expected `#` or `.`", c),
+            FarmError::MissingDimensions => write!(f, "region must look like `WxH`"),
+            FarmError::InvalidNumber(err) => write!(f, "invalid number: {}", err),
+            FarmError::UnknownShape(id) => write!(f, "region asks for unknown shape {}", id),
+        }
+    }
+}
+
+impl std::error::Error for FarmError {}
+
+/// Region width, height and how many of each present shape must fit in it.
+type Query = (usize, usize, Vec<usize>);
+
+/// The present shapes, each with all of its rotations and flips, and the
+/// regions under the trees that presents must fit into.
+pub struct TreeFarm {
+    shapes: Vec<Vec<Shape>>,
+    queries: Vec<Query>,
+}
+
+fn parse_number(text: &str, line: &Line) -> Result<usize, ParseError> {
+    let column = line.column + text.as_ptr() as usize - line.text.as_ptr() as usize;
+    text.parse()
+        .map_err(|err| ParseError::new(line.number, column, text, FarmError::InvalidNumber(err)))
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Vec<Shape>>, Vec<Query>), ParseError> {
+    let mut base_shapes = Vec::new();
+    let mut queries = Vec::new();
+
+    let mut lines = aoc_common::lines(input).peekable();
+
+    while let Some(line) = lines.next() {
+        let error = |kind| ParseError::new(line.number, line.column, line.text, kind);
+
+        let (head, tail) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| error(FarmError::UnexpectedLine))?;
+
+        if head.chars().all(|c| c.is_ascii_digit()) {
+            let id = parse_number(head, &line)?;
+            if id != base_shapes.len() {
+                return Err(error(FarmError::ShapeOutOfOrder {
+                    expected: base_shapes.len(),
+                    found: id,
+                }));
+            }
+
+            let mut points = Vec::new();
+            let mut r = 0;
+            let mut w = 0;
+
+            // Shape rows run until a blank line or the next `...:` header.
+            while let Some(shape_line) =
+                lines.next_if(|next| next.number == line.number + r + 1 && !next.text.contains(':'))
+            {
+                w = shape_line.text.len();
+                for (c, ch) in shape_line.text.chars().enumerate() {
+                    match ch {
+                        '#' => points.push((r, c)),
+                        '.' => {}
+                        _ => {
+                            return Err(ParseError::new(
+                                shape_line.number,
+                                shape_line.column + c,
+                                ch,
+                                FarmError::UnknownCell(ch),
+                            ));
+                        }
+                    }
+                }
+                r += 1;
+            }
+
+            let shape = Shape {
+                points,
+                width: w,
+                height: r,
+            }
+            .normalize();
+            // Pre-compute all variants for this shape ID
+            base_shapes.push(shape.generate_variants());
+            continue;
+        }
+
+        let (w_str, h_str) = head
+            .trim()
+            .split_once('x')
+            .ok_or_else(|| error(FarmError::MissingDimensions))?;
+        let w = parse_number(w_str, &line)?;
+        let h = parse_number(h_str, &line)?;
+
+        let mut reqs = Vec::new();
+        for n in tail.split_whitespace() {
+            let count = parse_number(n, &line)?;
+            if count > 0 && reqs.len() >= base_shapes.len() {
+                return Err(ParseError::new(
+                    line.number,
+                    line.column + n.as_ptr() as usize - line.text.as_ptr() as usize,
+                    n,
+                    FarmError::UnknownShape(reqs.len()),
+                ));
+            }
+            reqs.push(count);
+        }
+
+        if reqs.len() > base_shapes.len() {
+            aoc_common::warn(format!(
+                "line {}: region lists {} shape counts but only {} shapes are defined",
+                line.number,
+                reqs.len(),
+                base_shapes.len()
+            ));
+        }
+        queries.push((w, h, reqs));
+    }
+
+    Ok((base_shapes, queries))
+}
+
+fn solve_recursive(
+    grid: &mut Grid<bool>,
+    presents: &[usize],
+    variants_lookup: &[Vec<Shape>],
+    present_idx: usize,
+) -> bool {
+    if present_idx >= presents.len() {
+        return true;
+    }
+
+    let shape_id = presents[present_idx];
+    let possible_shapes = &variants_lookup[shape_id];
+
+    for r in 0..grid.height() {
+        for c in 0..grid.width() {
+            for shape_variant in possible_shapes {
+                if can_fit(grid, r, c, shape_variant) {
+                    toggle(grid, r, c, shape_variant);
+
+                    if solve_recursive(grid, presents, variants_lookup, present_idx + 1) {
+                        return true;
+                    }
+
+                    toggle(grid, r, c, shape_variant);
+                }
+            }
+        }
+    }
+
+    false
+}
+
+pub fn solve(farm: &TreeFarm) -> usize {
+    let base_shapes = &farm.shapes;
+    let mut solvable_count = 0;
+
+    for &(w, h, ref counts) in &farm.queries {
+        // Expand counts into a flat list of shape indices
+        let mut presents_to_fit = Vec::new();
+        let mut total_area = 0;
+
+        for (shape_id, &count) in counts.iter().enumerate() {
+            if shape_id < base_shapes.len() {
+                let area = base_shapes[shape_id][0].area();
+                for _ in 0..count {
+                    presents_to_fit.push((area, shape_id));
+                    total_area += area;
+                }
+            }
+        }
+
+        if total_area > w * h {
+            continue;
+        }
+
+        presents_to_fit.sort_by_key(|k| Reverse(k.0));
+        let sorted_indices: Vec<usize> = presents_to_fit.iter().map(|p| p.1).collect();
+
+        let mut grid = Grid::new(w, h, false);
+        if solve_recursive(&mut grid, &sorted_indices, base_shapes, 0) {
+            solvable_count += 1;
+        }
+    }
+
+    solvable_count
+}
+
+impl Solution for TreeFarm {
+    const DAY: u8 = 12;
+
+    type Input = TreeFarm;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (shapes, queries) = parse_input(input)?;
+        Ok(TreeFarm { shapes, queries })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(input).into())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer, Error> {
+        Err(Error::Missing {
+            day: Self::DAY,
+            part: 2,
+        })
+    }
+}
+
+/// Writes six random shapes of five to seven cells within 3x3 and `size`
+/// regions. Each region either has room for every present in its own 3x3
+/// square or less room than the presents' total area, so the answer to part 1
+/// is known without searching.
+impl Generate for TreeFarm {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        const SHAPES: usize = 6;
+
+        let mut areas = Vec::with_capacity(SHAPES);
+        for id in 0..SHAPES {
+            let mut cells = [true; 9];
+            let mut order: Vec<usize> = (0..9).collect();
+            rng.shuffle(&mut order);
+            let area = rng.range(5..=7) as usize;
+            for &cell in &order[area..] {
+                cells[cell] = false;
+            }
+            areas.push(area);
+
+            writeln!(out, "{}:", id)?;
+            for row in cells.chunks(3) {
+                let row: String = row.iter().map(|&c| if c { '#' } else { '.' }).collect();
+                writeln!(out, "{}", row)?;
+            }
+            writeln!(out)?;
+        }
+
+        let mut fitting = 0;
+        for _ in 0..size {
+            let width = rng.range(12..=50) as usize;
+            let height = rng.range(12..=50) as usize;
+            let mut counts = [0; SHAPES];
+
+            if rng.chance(0.5) {
+                fitting += 1;
+                let boxes = (width / 3) * (height / 3);
+                for _ in 0..rng.range(boxes as u64 / 2..=boxes as u64) {
+                    counts[rng.index(SHAPES)] += 1;
+                }
+            } else {
+                let mut area = 0;
+                while area <= width * height {
+                    let shape = rng.index(SHAPES);
+                    counts[shape] += 1;
+                    area += areas[shape];
+                }
+            }
+
+            let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+            writeln!(out, "{}x{}: {}", width, height, counts.join(" "))?;
+        }
+
+        Ok(vec![(1, fitting.into())])
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day12` binary.
+pub fn day() -> Day {
+    Day::of::<TreeFarm>().generating::<TreeFarm>()
+}
@@ -0,0 +1,759 @@
+use std::{collections::BTreeMap, fmt, io, num::ParseIntError, str::FromStr};
+
+use aoc_common::{
+    Answer, Error, Generate, Line, ParseError, Rng, Solution, Streaming,
+    runner::{Day, Flags},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    MissingHyphen,
+    InvalidId(ParseIntError),
+    /// Only reported with `--strict`.
+    Reversed {
+        start: u128,
+        stop: u128,
+    },
+    /// Only reported with `--strict`; the range is the union of the earlier
+    /// ranges it shares ids with.
+    Overlap {
+        start: u128,
+        stop: u128,
+    },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::MissingHyphen => write!(f, "range must look like `START-STOP`"),
+            RangeError::InvalidId(err) => write!(f, "invalid id: {}", err),
+            RangeError::Reversed { start, stop } => {
+                write!(f, "range starts at {} after it stops at {}", start, stop)
+            }
+            RangeError::Overlap { start, stop } => {
+                write!(f, "range overlaps the earlier ranges {}-{}", start, stop)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRange {
+    start: u128,
+    stop: u128,
+}
+
+impl IdRange {
+    /// Parses `START-STOP` with both ids written in `radix`, from 2 to 36.
+    pub fn parse_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        let (start_str, end_str) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(1, s, RangeError::MissingHyphen))?;
+        let parse_id = |id: &str, column: usize| {
+            u128::from_str_radix(id, radix)
+                .map_err(|err| ParseError::at(column, id, RangeError::InvalidId(err)))
+        };
+        Ok(IdRange {
+            start: parse_id(start_str, 1)?,
+            stop: parse_id(end_str, start_str.len() + 2)?,
+        })
+    }
+}
+
+impl FromStr for IdRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IdRange::parse_radix(s, 10)
+    }
+}
+
+/// The number of digits of `n` in `radix`, counting 0 as one digit.
+fn digit_count(n: u128, radix: u32) -> u32 {
+    n.checked_ilog(u128::from(radix)).map_or(1, |log| log + 1)
+}
+
+fn is_valid_for_pattern_len(n: u128, radix: u32, total_digits: u32, k: u32) -> bool {
+    let radix = u128::from(radix);
+    let repeats = total_digits / k;
+    let pattern = n / radix.pow(total_digits - k);
+
+    // Repeating a pattern past `u128::MAX` cannot give back `n`.
+    let Some(shift) = radix.checked_pow(k) else {
+        return repeats == 1;
+    };
+    let mut reconstructed: u128 = 0;
+    for _ in 0..repeats {
+        let Some(next) = reconstructed
+            .checked_mul(shift)
+            .and_then(|shifted| shifted.checked_add(pattern))
+        else {
+            return false;
+        };
+        reconstructed = next;
+    }
+
+    reconstructed == n
+}
+
+/// How many times an id's digit pattern must repeat for the id to be invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeats {
+    Exactly(u32),
+    AtLeast(u32),
+}
+
+/// Parses `R` for exactly `R` repeats and `R+` for at least `R`.
+impl FromStr for Repeats {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (count, at_least) = match s.strip_suffix('+') {
+            Some(count) => (count, true),
+            None => (s, false),
+        };
+        match count.parse() {
+            Ok(0) | Err(_) => Err(format!("invalid repeat count `{}`", s)),
+            Ok(count) if at_least => Ok(Repeats::AtLeast(count)),
+            Ok(count) => Ok(Repeats::Exactly(count)),
+        }
+    }
+}
+
+impl fmt::Display for Repeats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Repeats::Exactly(count) => write!(f, "{}", count),
+            Repeats::AtLeast(count) => write!(f, "{}+", count),
+        }
+    }
+}
+
+/// Whether the decimal digits of `n` are some pattern repeated as `repeats`
+/// asks.
+pub fn is_repeated(n: impl Into<u128>, repeats: Repeats) -> bool {
+    is_repeated_radix(n, 10, repeats)
+}
+
+/// Whether the digits of `n` written in `radix` are some pattern repeated as
+/// `repeats` asks.
+pub fn is_repeated_radix(n: impl Into<u128>, radix: u32, repeats: Repeats) -> bool {
+    let n = n.into();
+    let digit_count = digit_count(n, radix);
+    let is_pattern_len = |k: u32| {
+        digit_count.is_multiple_of(k) && is_valid_for_pattern_len(n, radix, digit_count, k)
+    };
+
+    match repeats {
+        Repeats::Exactly(count) => {
+            digit_count.is_multiple_of(count) && is_pattern_len(digit_count / count)
+        }
+        Repeats::AtLeast(count) => (1..=digit_count / count).any(is_pattern_len),
+    }
+}
+
+pub fn is_repeated_twice(n: impl Into<u128>) -> bool {
+    is_repeated(n, Repeats::Exactly(2))
+}
+
+pub fn is_repeated_sequence(n: impl Into<u128>) -> bool {
+    is_repeated(n, Repeats::AtLeast(2))
+}
+
+/// The Möbius function: 0 if `n` has a squared prime factor, otherwise -1 or 1
+/// for an odd or even number of prime factors.
+fn mobius(mut n: u32) -> i32 {
+    let mut sign = 1;
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        p += 1;
+    }
+    if n > 1 { -sign } else { sign }
+}
+
+fn divisors(n: u32) -> impl Iterator<Item = u32> {
+    (1..=n).filter(move |k| n.is_multiple_of(*k))
+}
+
+/// The first and last `k` digit patterns whose `digits` digit repetition lies
+/// in `start..=stop`, and what a pattern is multiplied by to repeat it, or
+/// `None` if there are none.
+fn pattern_span(
+    (start, stop): (u128, u128),
+    radix: u32,
+    digits: u32,
+    k: u32,
+) -> Option<(u128, u128, u128)> {
+    let radix = u128::from(radix);
+    let shift = radix.checked_pow(k);
+    let mut multiplier: u128 = 1;
+    for _ in 1..digits / k {
+        // Past `u128::MAX` even the smallest pattern is too large an id.
+        multiplier = shift
+            .and_then(|shift| multiplier.checked_mul(shift))
+            .and_then(|shifted| shifted.checked_add(1))?;
+    }
+
+    // `0` is the one single digit id with a leading zero.
+    let smallest = if digits == 1 { 0 } else { radix.pow(k - 1) };
+    let largest = shift.map_or(u128::MAX, |shift| shift - 1);
+    let first = start.div_ceil(multiplier).max(smallest);
+    let last = (stop / multiplier).min(largest);
+    (first <= last).then_some((first, last, multiplier))
+}
+
+/// Count and sum of the `digits` digit ids in `range`, written in
+/// `radix`, that are a `k` digit pattern repeated `digits / k` times, or
+/// `None` if they do not fit in a `u128`.
+///
+/// Those ids are the patterns times `1 0..01 0..01...`, so they are found by
+/// dividing the bounds instead of trying every id.
+fn patterns_in(range: (u128, u128), radix: u32, digits: u32, k: u32) -> Option<(u128, u128)> {
+    let Some((first, last, multiplier)) = pattern_span(range, radix, digits, k) else {
+        return Some((0, 0));
+    };
+
+    let count = (last - first).checked_add(1)?;
+    let ends = first.checked_add(last)?;
+    // One of the two is even, so the sum of `first..=last` halves exactly.
+    let patterns = if count.is_multiple_of(2) {
+        (count / 2).checked_mul(ends)?
+    } else {
+        count.checked_mul(ends / 2)?
+    };
+    Some((count, patterns.checked_mul(multiplier)?))
+}
+
+/// A 256 bit total, as the high and low halves, so that inclusion–exclusion
+/// can add past `u128::MAX` before taking away.
+#[derive(Debug, Default, Clone, Copy)]
+struct Wide(u128, u128);
+
+impl Wide {
+    fn add(&mut self, value: u128) {
+        let (low, carry) = self.1.overflowing_add(value);
+        self.0 += u128::from(carry);
+        self.1 = low;
+    }
+
+    /// `self - other`, if it is no larger than `u128::MAX`.
+    fn minus(self, other: Wide) -> Option<u128> {
+        let (low, borrow) = self.1.overflowing_sub(other.1);
+        let high = self
+            .0
+            .checked_sub(other.0)?
+            .checked_sub(u128::from(borrow))?;
+        (high == 0).then_some(low)
+    }
+}
+
+/// Accumulates counts and sums added and taken away by inclusion–exclusion.
+#[derive(Debug, Default)]
+struct Tally {
+    added: (Wide, Wide),
+    removed: (Wide, Wide),
+}
+
+impl Tally {
+    fn add(&mut self, (count, sum): (u128, u128), sign: i32) {
+        let side = match sign {
+            0 => return,
+            1 => &mut self.added,
+            _ => &mut self.removed,
+        };
+        side.0.add(count);
+        side.1.add(sum);
+    }
+
+    fn total(&self) -> Option<(u128, u128)> {
+        Some((
+            self.added.0.minus(self.removed.0)?,
+            self.added.1.minus(self.removed.1)?,
+        ))
+    }
+}
+
+/// The rules of parts 1 and 2, unless `--repeats` picks one for both.
+const RULES: [Repeats; 2] = [Repeats::Exactly(2), Repeats::AtLeast(2)];
+
+impl IdRange {
+    pub fn start(&self) -> u128 {
+        self.start
+    }
+
+    pub fn stop(&self) -> u128 {
+        self.stop
+    }
+
+    /// Sums the ids in the range that `invalid` rejects, trying every one.
+    pub fn invalid_sum(&self, invalid: impl Fn(u128) -> bool) -> u128 {
+        (self.start..=self.stop).filter(|&id| invalid(id)).sum()
+    }
+
+    /// Counts and sums the ids in the range that `repeats` makes invalid,
+    /// without visiting them.
+    ///
+    /// The ids of one length repeating a pattern of length `k` are a simple
+    /// arithmetic sequence. An id repeats every pattern whose length is a
+    /// multiple of its shortest one, so for at least `R` repeats the ids are
+    /// split by shortest pattern length `m` and counted once each: by Möbius
+    /// inversion, `shortest(m) = sum of mobius(m / j) * repeating(j)` over the
+    /// divisors `j` of `m`.
+    ///
+    /// Ids are read in `radix`; returns `None` if the count or sum does not
+    /// fit in a `u128`.
+    pub fn invalid_count_and_sum(&self, radix: u32, repeats: Repeats) -> Option<(u128, u128)> {
+        let mut tally = Tally::default();
+        for digits in 1..=digit_count(u128::MAX, radix) {
+            let mut add = |k: u32, sign: i32| {
+                tally.add(
+                    patterns_in((self.start, self.stop), radix, digits, k)?,
+                    sign,
+                );
+                Some(())
+            };
+            match repeats {
+                Repeats::Exactly(r) if digits.is_multiple_of(r) => add(digits / r, 1)?,
+                Repeats::Exactly(_) => {}
+                Repeats::AtLeast(r) => {
+                    for shortest in divisors(digits).filter(|&m| m <= digits / r) {
+                        for k in divisors(shortest) {
+                            add(k, mobius(shortest / k))?;
+                        }
+                    }
+                }
+            }
+        }
+        tally.total()
+    }
+}
+
+/// `n` written in `radix`, with lowercase letters past 9.
+struct Digits(u128, u32);
+
+impl fmt::Display for Digits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Digits(n, radix) = *self;
+        let radix_wide = u128::from(radix);
+        let mut place = radix_wide.pow(digit_count(n, radix) - 1);
+        while place > 0 {
+            let digit = (n / place % radix_wide) as u32;
+            write!(
+                f,
+                "{}",
+                char::from_digit(digit, radix).expect("digit below radix")
+            )?;
+            place /= radix_wide;
+        }
+        Ok(())
+    }
+}
+
+/// The shortest pattern that `n`, written in `radix`, repeats, and how many
+/// times it repeats it.
+fn shortest_unit(n: u128, radix: u32) -> (u128, u32) {
+    let digits = digit_count(n, radix);
+    let k = divisors(digits)
+        .find(|&k| is_valid_for_pattern_len(n, radix, digits, k))
+        .unwrap_or(digits);
+    (n / u128::from(radix).pow(digits - k), digits / k)
+}
+
+/// An invalid id and the shortest pattern it repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidId {
+    pub id: u128,
+    pub unit: u128,
+    pub repeats: u32,
+    radix: u32,
+}
+
+/// Prints `1212 = 12 ×2`, in the radix the id was read in.
+impl fmt::Display for InvalidId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {} ×{}",
+            Digits(self.id, self.radix),
+            Digits(self.unit, self.radix),
+            self.repeats
+        )
+    }
+}
+
+/// The ids repeating one pattern length, as the patterns left to repeat.
+struct Patterns {
+    next: Option<u128>,
+    last: u128,
+    multiplier: u128,
+}
+
+/// The invalid ids of a range in increasing order, found a digit count at a
+/// time from the repeated patterns rather than by trying every id.
+pub struct InvalidIds {
+    range: IdRange,
+    radix: u32,
+    repeats: Repeats,
+    digits: u32,
+    /// One sequence per pattern length for the current digit count.
+    patterns: Vec<Patterns>,
+}
+
+impl InvalidIds {
+    /// Starts the sequences of ids with `digits` digits.
+    fn start_digits(&mut self, digits: u32) {
+        self.digits = digits;
+        let lengths: Vec<u32> = match self.repeats {
+            Repeats::Exactly(r) if digits.is_multiple_of(r) => vec![digits / r],
+            Repeats::Exactly(_) => Vec::new(),
+            Repeats::AtLeast(r) => divisors(digits).filter(|&k| k <= digits / r).collect(),
+        };
+        let span = (self.range.start, self.range.stop);
+        self.patterns = lengths
+            .into_iter()
+            .filter_map(|k| pattern_span(span, self.radix, digits, k))
+            .map(|(first, last, multiplier)| Patterns {
+                next: Some(first),
+                last,
+                multiplier,
+            })
+            .collect();
+    }
+}
+
+impl Iterator for InvalidIds {
+    type Item = InvalidId;
+
+    fn next(&mut self) -> Option<InvalidId> {
+        loop {
+            let Some(patterns) = self
+                .patterns
+                .iter_mut()
+                .filter(|patterns| patterns.next.is_some())
+                .min_by_key(|patterns| patterns.next.map(|p| p * patterns.multiplier))
+            else {
+                if self.digits == digit_count(u128::MAX, self.radix) {
+                    return None;
+                }
+                self.start_digits(self.digits + 1);
+                continue;
+            };
+
+            let pattern = patterns.next?;
+            let id = pattern * patterns.multiplier;
+            patterns.next = (pattern < patterns.last).then(|| pattern + 1);
+            let (unit, repeats) = shortest_unit(id, self.radix);
+            // With several pattern lengths an id is only taken from the
+            // sequence of its shortest one, so it comes once.
+            if let Repeats::AtLeast(_) = self.repeats
+                && patterns.multiplier != id / unit
+            {
+                continue;
+            }
+            return Some(InvalidId {
+                id,
+                unit,
+                repeats,
+                radix: self.radix,
+            });
+        }
+    }
+}
+
+impl IdRange {
+    /// The ids in the range that `repeats` makes invalid, read in `radix`,
+    /// lazily and in increasing order.
+    pub fn invalid_ids(&self, radix: u32, repeats: Repeats) -> InvalidIds {
+        let mut ids = InvalidIds {
+            range: *self,
+            radix,
+            repeats,
+            digits: 0,
+            patterns: Vec::new(),
+        };
+        ids.start_digits(1);
+        ids
+    }
+}
+
+/// Sorted, disjoint ranges of ids, merged as ranges are added so that no id
+/// is counted twice.
+#[derive(Debug, Default)]
+pub struct RangeSet(BTreeMap<u128, u128>);
+
+impl RangeSet {
+    /// Adds `range`, merging it with the ranges it overlaps or touches.
+    ///
+    /// Returns the span of the ranges it shared ids with, if any. Empty
+    /// ranges are ignored.
+    pub fn insert(&mut self, range: &IdRange) -> Option<IdRange> {
+        if range.start > range.stop {
+            return None;
+        }
+
+        let (mut start, mut stop) = (range.start, range.stop);
+        let mut shared: Option<IdRange> = None;
+        // Ranges starting past `stop + 1` cannot touch; walk back from there.
+        let touching: Vec<(u128, u128)> = self
+            .0
+            .range(..=range.stop.saturating_add(1))
+            .rev()
+            .take_while(|&(_, &end)| end.saturating_add(1) >= range.start)
+            .map(|(&begin, &end)| (begin, end))
+            .collect();
+        for (begin, end) in touching {
+            self.0.remove(&begin);
+            if begin <= range.stop && end >= range.start {
+                let span = shared.get_or_insert(IdRange {
+                    start: begin,
+                    stop: end,
+                });
+                span.start = span.start.min(begin);
+                span.stop = span.stop.max(end);
+            }
+            start = start.min(begin);
+            stop = stop.max(end);
+        }
+
+        self.0.insert(start, stop);
+        shared
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = IdRange> {
+        self.0.iter().map(|(&start, &stop)| IdRange { start, stop })
+    }
+}
+
+/// What `--report` prints for each input range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Report {
+    /// The count and sum of its invalid ids.
+    Counts,
+    /// The counts, then every invalid id with the pattern it repeats.
+    Ids,
+}
+
+impl FromStr for Report {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "counts" => Ok(Report::Counts),
+            "ids" => Ok(Report::Ids),
+            _ => Err(format!("unknown report `{}`", s)),
+        }
+    }
+}
+
+/// The merged input ranges and the rules each part applies to them.
+pub struct Ranges {
+    ranges: RangeSet,
+    rules: [Repeats; 2],
+    /// The base ids are written in, for `--radix`.
+    radix: u32,
+    /// Whether overlapping and reversed ranges are errors, for `--strict`.
+    strict: bool,
+    report: Option<Report>,
+    /// The input ranges as written, kept for the report.
+    reported: Vec<IdRange>,
+}
+
+impl Ranges {
+    /// Takes `--repeats=R` or `--repeats=R+` to answer both parts under the
+    /// rule of exactly or at least `R` repeats, `--radix=N` for ids written in
+    /// base `N`, `--strict` and `--report=counts|ids`.
+    fn from_flags(flags: &mut Flags) -> Result<Self, Error> {
+        let radix = flags.value("radix")?.unwrap_or(10);
+        if !(2..=36).contains(&radix) {
+            return Err(Error::Usage("--radix must be from 2 to 36".to_owned()));
+        }
+        Ok(Ranges {
+            ranges: RangeSet::default(),
+            rules: flags
+                .value("repeats")?
+                .map_or(RULES, |repeats| [repeats; 2]),
+            radix,
+            strict: flags.switch("strict")?,
+            report: flags.value("report")?,
+            reported: Vec::new(),
+        })
+    }
+
+    /// Adds the comma separated ranges on `line`.
+    fn add(&mut self, line: Line<'_>) -> Result<(), ParseError> {
+        for field in line.split(',') {
+            let range = IdRange::parse_radix(field.text, self.radix)
+                .map_err(|err| err.relative_to(&field))?;
+            let error = |kind| {
+                Err(ParseError::new(
+                    field.number,
+                    field.column,
+                    field.text,
+                    kind,
+                ))
+            };
+            if self.strict && range.start > range.stop {
+                return error(RangeError::Reversed {
+                    start: range.start,
+                    stop: range.stop,
+                });
+            }
+            if let Some(shared) = self.ranges.insert(&range)
+                && self.strict
+            {
+                return error(RangeError::Overlap {
+                    start: shared.start,
+                    stop: shared.stop,
+                });
+            }
+            if self.report.is_some() {
+                self.reported.push(range);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the invalid ids of every input range, in input order, under
+    /// each rule.
+    fn write_report(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        let Some(report) = self.report else {
+            return Ok(());
+        };
+        let [first, second] = self.rules;
+        let rules = if first == second {
+            &self.rules[..1]
+        } else {
+            &self.rules[..]
+        };
+        for range in &self.reported {
+            for &repeats in rules {
+                let span = format!(
+                    "{}-{}, repeated {} times",
+                    Digits(range.start, self.radix),
+                    Digits(range.stop, self.radix),
+                    repeats
+                );
+                match range.invalid_count_and_sum(self.radix, repeats) {
+                    Some((count, sum)) => {
+                        writeln!(out, "{}: {} invalid, sum {}", span, count, sum)?
+                    }
+                    None => writeln!(out, "{}: too many invalid ids to sum in 128 bits", span)?,
+                }
+                if report == Report::Ids {
+                    for id in range.invalid_ids(self.radix, repeats) {
+                        writeln!(out, "  {}", id)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn invalid_sum(&self, part: u8) -> Result<Answer, Error> {
+        let repeats = self.rules[usize::from(part - 1)];
+        let mut sum: u128 = 0;
+        for range in self.ranges.iter() {
+            sum = range
+                .invalid_count_and_sum(self.radix, repeats)
+                .and_then(|(_, range_sum)| sum.checked_add(range_sum))
+                .ok_or_else(|| {
+                    Error::NoSolution("the sum of invalid ids does not fit in 128 bits".to_owned())
+                })?;
+        }
+        Ok(sum.into())
+    }
+}
+
+impl Solution for IdRange {
+    const DAY: u8 = 2;
+
+    type Input = Ranges;
+
+    const FLAGS: &[&str] = &["repeats", "radix", "strict", "report"];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Self::parse_with(input, &mut Flags::default())
+    }
+
+    fn parse_with(input: &str, flags: &mut Flags) -> Result<Self::Input, Error> {
+        let mut ranges = Ranges::from_flags(flags)?;
+        for line in aoc_common::lines(input) {
+            ranges.add(line)?;
+        }
+        Ok(ranges)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        input.invalid_sum(1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        input.invalid_sum(2)
+    }
+
+    fn details(input: &Self::Input, out: &mut dyn io::Write) -> io::Result<()> {
+        input.write_report(out)
+    }
+}
+
+impl Streaming for IdRange {
+    type State = Ranges;
+
+    type Item = ();
+
+    /// The input is one long line of ranges, so it is read a range at a time.
+    const SEPARATOR: Option<u8> = Some(b',');
+
+    fn start(flags: &mut Flags) -> Result<Self::State, Error> {
+        Ranges::from_flags(flags)
+    }
+
+    fn feed(state: &mut Self::State, line: Line<'_>) -> Result<(), ParseError> {
+        state.add(line)
+    }
+
+    fn answer(state: &Self::State, part: u8) -> Result<Answer, Error> {
+        state.invalid_sum(part)
+    }
+
+    fn state_details(state: &Self::State, out: &mut dyn io::Write) -> io::Result<()> {
+        state.write_report(out)
+    }
+}
+
+/// Writes `size` ranges of up to a thousand ids, with ids of up to ten digits,
+/// putting ten ranges on each line.
+impl Generate for IdRange {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        for i in 0..size {
+            let digits = rng.range(1..=10) as u32;
+            let start = rng.range(10_u64.pow(digits - 1)..=10_u64.pow(digits) - 1);
+            let stop = start + rng.below(1000);
+            let separator = if i + 1 == size || i % 10 == 9 {
+                "\n"
+            } else {
+                ","
+            };
+            write!(out, "{}-{}{}", start, stop, separator)?;
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day2` binary.
+pub fn day() -> Day {
+    Day::streaming::<IdRange>().generating::<IdRange>()
+}
@@ -0,0 +1,108 @@
+//! Compares the arithmetic count and sum of invalid ids with filtering every
+//! id of the range.
+
+use aoc_common::Rng;
+use day2::{IdRange, Repeats, is_repeated_radix};
+
+/// Count and sum of the invalid ids, or `None` if the sum overflows.
+fn brute_force(start: u128, stop: u128, radix: u32, repeats: Repeats) -> Option<(u128, u128)> {
+    (start..=stop)
+        .filter(|&id| is_repeated_radix(id, radix, repeats))
+        .try_fold((0, 0), |(count, sum): (u128, u128), id| {
+            Some((count + 1, sum.checked_add(id)?))
+        })
+}
+
+const MODES: [Repeats; 8] = [
+    Repeats::Exactly(1),
+    Repeats::Exactly(2),
+    Repeats::Exactly(3),
+    Repeats::Exactly(4),
+    Repeats::AtLeast(1),
+    Repeats::AtLeast(2),
+    Repeats::AtLeast(3),
+    Repeats::AtLeast(5),
+];
+
+fn agree_in(start: u128, stop: u128, radix: u32) {
+    let range: IdRange = format!("{}-{}", start, stop).parse().unwrap();
+    for repeats in MODES {
+        assert_eq!(
+            range.invalid_count_and_sum(radix, repeats),
+            brute_force(start, stop, radix, repeats),
+            "{}-{} in base {} repeated {} times",
+            start,
+            stop,
+            radix,
+            repeats
+        );
+    }
+}
+
+fn agree(start: u64, stop: u64) {
+    agree_in(start.into(), stop.into(), 10);
+}
+
+#[test]
+fn small_ranges_match_brute_force() {
+    for start in 0..120 {
+        for stop in start..start + 120 {
+            agree(start, stop);
+        }
+    }
+    agree(0, 200_000);
+}
+
+#[test]
+fn random_ranges_match_brute_force() {
+    let mut rng = Rng::new(7);
+    for _ in 0..200 {
+        let digits = rng.range(1..=19) as u32;
+        let start = rng.range(0..=10_u64.pow(digits));
+        let stop = start.saturating_add(rng.below(10_000));
+        agree(start, stop);
+    }
+}
+
+#[test]
+fn ranges_ending_at_the_largest_id_match_brute_force() {
+    agree(u64::MAX - 50_000, u64::MAX);
+    // `u64::MAX` has 20 digits, the only length where `10^digits` overflows.
+    agree(10_000_000_000_000_000_000, 10_000_000_000_000_100_000);
+}
+
+#[test]
+fn empty_ranges_have_no_invalid_ids() {
+    let range: IdRange = "100-10".parse().unwrap();
+    for repeats in MODES {
+        assert_eq!(range.invalid_count_and_sum(10, repeats), Some((0, 0)));
+    }
+}
+
+#[test]
+fn other_bases_match_brute_force() {
+    let mut rng = Rng::new(9);
+    for radix in [2, 3, 7, 16, 36] {
+        agree_in(0, 5_000, radix);
+        for _ in 0..20 {
+            let start = u128::from(rng.next_u64()) << rng.below(64);
+            agree_in(start, start + u128::from(rng.below(2_000)), radix);
+        }
+        agree_in(u128::MAX - 2_000, u128::MAX, radix);
+    }
+}
+
+#[test]
+fn ids_are_read_in_the_given_base() {
+    let range = IdRange::parse_radix("ff-1ff", 16).unwrap();
+    assert_eq!((range.start(), range.stop()), (255, 511));
+    assert!(is_repeated_radix(0xabab_u32, 16, Repeats::Exactly(2)));
+    assert!(!is_repeated_radix(0xabab_u32, 10, Repeats::Exactly(2)));
+    assert!(is_repeated_radix(0b1010_1010_u8, 2, Repeats::AtLeast(4)));
+}
+
+#[test]
+fn overflowing_sums_are_reported() {
+    let range = IdRange::parse_radix("0-ffffffffffffffffffffffffffffffff", 16).unwrap();
+    assert_eq!(range.invalid_count_and_sum(16, Repeats::AtLeast(2)), None);
+}
@@ -0,0 +1,53 @@
+//! Checks that merged ranges cover every id of the input exactly once.
+
+use std::collections::BTreeSet;
+
+use aoc_common::Rng;
+use day2::{IdRange, RangeSet};
+
+#[test]
+fn merged_ranges_cover_the_same_ids_once() {
+    let mut rng = Rng::new(8);
+    for _ in 0..500 {
+        let mut set = RangeSet::default();
+        let mut ids = BTreeSet::new();
+        for _ in 0..rng.range(1..=8) {
+            let start = u128::from(rng.range(0..=60));
+            let stop = start + u128::from(rng.range(0..=15));
+            let range: IdRange = format!("{}-{}", start, stop).parse().unwrap();
+
+            let shares_ids = (start..=stop).any(|id| ids.contains(&id));
+            assert_eq!(
+                set.insert(&range).is_some(),
+                shares_ids,
+                "{}-{}",
+                start,
+                stop
+            );
+            ids.extend(start..=stop);
+        }
+
+        let mut covered = Vec::new();
+        let mut last_stop = None;
+        for range in set.iter() {
+            let (start, stop) = (range.start(), range.stop());
+            assert!(start <= stop, "{:?}", range);
+            // Touching ranges are merged too, so a gap always separates them.
+            assert!(
+                last_stop.is_none_or(|last: u128| last + 1 < start),
+                "{:?}",
+                range
+            );
+            last_stop = Some(stop);
+            covered.extend(start..=stop);
+        }
+        assert_eq!(covered, ids.into_iter().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn reversed_ranges_are_empty() {
+    let mut set = RangeSet::default();
+    assert!(set.insert(&"30-20".parse().unwrap()).is_none());
+    assert_eq!(set.iter().count(), 0);
+}
@@ -0,0 +1,51 @@
+//! Checks how `--repeats` rules are written and which ids they reject.
+
+use day2::{Repeats, is_repeated, is_repeated_sequence, is_repeated_twice};
+
+#[test]
+fn rules_parse_and_print() {
+    assert_eq!("2".parse(), Ok(Repeats::Exactly(2)));
+    assert_eq!("3+".parse(), Ok(Repeats::AtLeast(3)));
+    for bad in ["0", "0+", "", "+", "two", "2++", "-2", "2 "] {
+        assert!(bad.parse::<Repeats>().is_err(), "{:?} parses", bad);
+    }
+    assert_eq!(Repeats::Exactly(2).to_string(), "2");
+    assert_eq!(Repeats::AtLeast(3).to_string(), "3+");
+}
+
+#[test]
+fn exactly_takes_that_many_repeats() {
+    let twice = Repeats::Exactly(2);
+    for id in [11_u64, 6464, 123123, 1188511885] {
+        assert!(is_repeated(id, twice), "{}", id);
+    }
+    for id in [1_u64, 101, 111, 12312, 123123123] {
+        assert!(!is_repeated(id, twice), "{}", id);
+    }
+
+    let thrice = Repeats::Exactly(3);
+    assert!(is_repeated(111_u64, thrice));
+    assert!(is_repeated(123123123_u64, thrice));
+    assert!(!is_repeated(1111_u64, thrice));
+    // Six ones are both two 111s and three 11s.
+    assert!(is_repeated(111111_u64, thrice) && is_repeated(111111_u64, twice));
+}
+
+#[test]
+fn at_least_takes_any_more_repeats() {
+    let two_or_more = Repeats::AtLeast(2);
+    for id in [11_u64, 111, 1212, 121212, 824824824, 2121212121] {
+        assert!(is_repeated(id, two_or_more), "{}", id);
+    }
+    for id in [7_u64, 12, 1231, 12121] {
+        assert!(!is_repeated(id, two_or_more), "{}", id);
+    }
+
+    let three_or_more = Repeats::AtLeast(3);
+    assert!(is_repeated(999_u64, three_or_more));
+    assert!(is_repeated(12121212_u64, three_or_more));
+    assert!(!is_repeated(123123_u64, three_or_more));
+
+    assert!(is_repeated_twice(1010_u64) && !is_repeated_twice(101010_u64));
+    assert!(is_repeated_sequence(101010_u64));
+}
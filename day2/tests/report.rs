@@ -0,0 +1,77 @@
+//! Checks the lazily listed invalid ids against trying every id.
+
+use aoc_common::Rng;
+use day2::{IdRange, Repeats, is_repeated_radix};
+
+#[test]
+fn invalid_ids_match_trying_every_id() {
+    let mut rng = Rng::new(22);
+    for _ in 0..300 {
+        let radix = rng.range(2..=16) as u32;
+        let start = u128::from(rng.range(0..=100_000));
+        let stop = start + u128::from(rng.range(0..=2_000));
+        let range = IdRange::parse_radix(
+            &format!("{}-{}", to_radix(start, radix), to_radix(stop, radix)),
+            radix,
+        )
+        .unwrap();
+        for repeats in [
+            Repeats::Exactly(2),
+            Repeats::Exactly(3),
+            Repeats::AtLeast(2),
+            Repeats::AtLeast(3),
+        ] {
+            let expected: Vec<u128> = (start..=stop)
+                .filter(|&id| is_repeated_radix(id, radix, repeats))
+                .collect();
+            let found: Vec<u128> = range
+                .invalid_ids(radix, repeats)
+                .map(|invalid| invalid.id)
+                .collect();
+            assert_eq!(found, expected, "{:?} in base {} {}", range, radix, repeats);
+        }
+    }
+}
+
+#[test]
+fn units_are_the_shortest_patterns() {
+    let range: IdRange = "1-100000".parse().unwrap();
+    for invalid in range.invalid_ids(10, Repeats::AtLeast(2)) {
+        let unit = invalid.unit.to_string();
+        assert_eq!(
+            unit.repeat(invalid.repeats as usize),
+            invalid.id.to_string()
+        );
+        assert!(!is_repeated_radix(invalid.unit, 10, Repeats::AtLeast(2)));
+    }
+    let ids: Vec<String> = "121212-121212"
+        .parse::<IdRange>()
+        .unwrap()
+        .invalid_ids(10, Repeats::Exactly(3))
+        .map(|invalid| invalid.to_string())
+        .collect();
+    assert_eq!(ids, ["121212 = 12 ×3"]);
+}
+
+#[test]
+fn huge_ranges_are_listed_lazily() {
+    let range: IdRange = format!("0-{}", u128::MAX).parse().unwrap();
+    let first: Vec<u128> = range
+        .invalid_ids(10, Repeats::AtLeast(2))
+        .take(12)
+        .map(|invalid| invalid.id)
+        .collect();
+    assert_eq!(first, [11, 22, 33, 44, 55, 66, 77, 88, 99, 111, 222, 333]);
+}
+
+fn to_radix(mut n: u128, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((n % u128::from(radix)) as u32, radix).unwrap());
+        n /= u128::from(radix);
+        if n == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
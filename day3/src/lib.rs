@@ -0,0 +1,424 @@
+use std::{fmt, io, str::FromStr};
+
+use aoc_common::{
+    Answer, Error, Generate, Line, ParseError, Rng, Solution, Streaming,
+    runner::{Day, Flags},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BankError {
+    InvalidJoltage(char),
+}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BankError::InvalidJoltage(c) => {
+                write!(f, "battery joltage `{}` is not a digit from 1 to 9", c)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BankError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatteryBank {
+    batteries: Vec<u8>,
+}
+
+impl FromStr for BatteryBank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .char_indices()
+            .map(|(i, c)| match c {
+                '1'..='9' => Ok(c as u8 - b'0'),
+                _ => Err(ParseError::at(i + 1, c, BankError::InvalidJoltage(c))),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(BatteryBank { batteries: digits })
+    }
+}
+
+/// A joltage as its decimal digits, most significant first, so it is exact
+/// however many batteries are on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Joltage(Vec<u8>);
+
+impl Joltage {
+    pub fn digits(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Joltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("0");
+        }
+        for &digit in &self.0 {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// An exact sum of joltages of any length.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JoltageSum {
+    /// Decimal digits, least significant first.
+    digits: Vec<u8>,
+}
+
+impl JoltageSum {
+    pub fn add(&mut self, joltage: &Joltage) {
+        if self.digits.len() < joltage.0.len() {
+            self.digits.resize(joltage.0.len(), 0);
+        }
+        let mut carry = 0;
+        let mut added = joltage.0.iter().rev();
+        for digit in &mut self.digits {
+            let next = added.next();
+            if next.is_none() && carry == 0 {
+                return;
+            }
+            let total = *digit + next.copied().unwrap_or(0) + carry;
+            *digit = total % 10;
+            carry = total / 10;
+        }
+        if carry > 0 {
+            self.digits.push(carry);
+        }
+    }
+}
+
+impl fmt::Display for JoltageSum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.digits.is_empty() {
+            return f.write_str("0");
+        }
+        for &digit in self.digits.iter().rev() {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// The batteries turned on for the largest joltage of a bank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// The positions of the batteries in the bank, from 0 and in order.
+    pub indices: Vec<usize>,
+    pub joltage: Joltage,
+}
+
+/// How `--show` marks the batteries turned on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// Reverse video, for a terminal.
+    Ansi,
+    /// Square brackets around each run of batteries turned on.
+    Brackets,
+}
+
+impl FromStr for Highlight {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Highlight::Ansi),
+            "brackets" => Ok(Highlight::Brackets),
+            _ => Err(format!("unknown highlight `{}`", s)),
+        }
+    }
+}
+
+impl Highlight {
+    /// What goes before and after each run of highlighted batteries.
+    fn marks(self) -> (&'static str, &'static str) {
+        match self {
+            Highlight::Ansi => ("\x1b[7m", "\x1b[0m"),
+            Highlight::Brackets => ("[", "]"),
+        }
+    }
+}
+
+impl BatteryBank {
+    /// The `k` batteries to turn on for the largest joltage, read in bank
+    /// order, or `None` if the bank has fewer than `k`.
+    ///
+    /// Keeps a stack of the batteries chosen so far and, while batteries can
+    /// still be skipped, drops any smaller than the next one, so the bank is
+    /// read once.
+    pub fn select(&self, k: usize) -> Option<Selection> {
+        let mut skips = self.batteries.len().checked_sub(k)?;
+        let mut indices: Vec<usize> = Vec::with_capacity(k);
+        for (i, &battery) in self.batteries.iter().enumerate() {
+            while skips > 0
+                && indices
+                    .last()
+                    .is_some_and(|&top| self.batteries[top] < battery)
+            {
+                indices.pop();
+                skips -= 1;
+            }
+            if indices.len() < k {
+                indices.push(i);
+            } else {
+                skips -= 1;
+            }
+        }
+
+        let joltage = Joltage(indices.iter().map(|&i| self.batteries[i]).collect());
+        Some(Selection { indices, joltage })
+    }
+
+    /// The largest joltage from turning on `k` batteries, or `None` if the
+    /// bank has fewer than `k`.
+    pub fn max_joltage(&self, k: usize) -> Option<Joltage> {
+        self.select(k).map(|selection| selection.joltage)
+    }
+
+    /// The bank's digits with the batteries at `indices`, in order, marked.
+    pub fn highlight(&self, indices: &[usize], highlight: Highlight) -> String {
+        let (open, close) = highlight.marks();
+        let mut out = String::with_capacity(self.batteries.len());
+        let mut selected = indices.iter().peekable();
+        let mut in_run = false;
+        for (i, &battery) in self.batteries.iter().enumerate() {
+            let on = selected.next_if_eq(&&i).is_some();
+            if on != in_run {
+                out.push_str(if on { open } else { close });
+                in_run = on;
+            }
+            out.push(char::from(b'0' + battery));
+        }
+        if in_run {
+            out.push_str(close);
+        }
+        out
+    }
+
+    /// [`BatteryBank::max_joltage`] by picking each digit as the largest in
+    /// the window that leaves enough batteries for the rest, in `O(n·k)`.
+    /// Only exact for `k` up to 19.
+    pub fn max_joltage_windowed(&self, k: usize) -> Option<u64> {
+        let mut cursor = 0;
+        let mut result: u64 = 0;
+        let n = self.batteries.len();
+        if n < k {
+            return None;
+        }
+
+        for remaining in (1..=k).rev() {
+            let limit = n - remaining;
+            let window = &self.batteries[cursor..=limit];
+
+            let (offset, &digit) = window
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|&(_, val)| val)
+                .expect("Window should never be empty");
+
+            result = result * 10 + (digit as u64);
+
+            cursor += offset + 1;
+        }
+
+        Some(result)
+    }
+}
+
+/// How many batteries each part turns on per bank, unless `--k` changes part
+/// 2's.
+const BATTERIES: [usize; 2] = [2, 12];
+
+/// Running joltage totals of both parts.
+#[derive(Debug)]
+pub struct Joltages {
+    batteries: [usize; 2],
+    banks: usize,
+    totals: [JoltageSum; 2],
+    /// The first bank too short for each part, by 1-based position.
+    too_short: [Option<usize>; 2],
+    show: Option<Highlight>,
+    /// Every bank and the batteries part 2 turns on in it, kept for `--show`.
+    shown: Vec<(BatteryBank, Option<Selection>)>,
+}
+
+impl Default for Joltages {
+    fn default() -> Self {
+        Joltages {
+            batteries: BATTERIES,
+            banks: 0,
+            totals: Default::default(),
+            too_short: [None; 2],
+            show: None,
+            shown: Vec::new(),
+        }
+    }
+}
+
+impl Joltages {
+    /// Takes `--k=N` for part 2 to turn on `N` batteries per bank, and
+    /// `--show=ansi|brackets` to write each bank with part 2's batteries
+    /// marked as details.
+    fn from_flags(flags: &mut Flags) -> Result<Self, Error> {
+        let mut joltages = Joltages {
+            show: flags.value("show")?,
+            ..Joltages::default()
+        };
+        if let Some(k) = flags.value("k")? {
+            if k == 0 {
+                return Err(Error::Usage("--k must be at least 1".to_owned()));
+            }
+            joltages.batteries[1] = k;
+        }
+        Ok(joltages)
+    }
+
+    fn add(&mut self, bank: BatteryBank) {
+        self.banks += 1;
+        self.select(&bank, 1);
+        let selection = self.select(&bank, 2);
+        if self.show.is_some() {
+            self.shown.push((bank, selection));
+        }
+    }
+
+    /// Adds the batteries `part` turns on in `bank`, the latest one, to its
+    /// total.
+    fn select(&mut self, bank: &BatteryBank, part: u8) -> Option<Selection> {
+        let i = usize::from(part - 1);
+        let Some(selected) = bank.select(self.batteries[i]) else {
+            self.too_short[i].get_or_insert(self.banks);
+            return None;
+        };
+        self.totals[i].add(&selected.joltage);
+        Some(selected)
+    }
+
+    /// Writes every bank with the batteries part 2 turns on marked, and the
+    /// joltage they give.
+    fn write_shown(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        let Some(highlight) = self.show else {
+            return Ok(());
+        };
+        for (number, (bank, selection)) in (1..).zip(&self.shown) {
+            match selection {
+                Some(selection) => writeln!(
+                    out,
+                    "bank {}: {} = {}",
+                    number,
+                    bank.highlight(&selection.indices, highlight),
+                    selection.joltage
+                )?,
+                None => writeln!(
+                    out,
+                    "bank {}: fewer than {} batteries",
+                    number, self.batteries[1]
+                )?,
+            }
+        }
+        Ok(())
+    }
+
+    fn total(&self, part: u8) -> Result<Answer, Error> {
+        let i = usize::from(part - 1);
+        match self.too_short[i] {
+            Some(bank) => Err(Error::NoSolution(format!(
+                "bank {} has fewer than {} batteries",
+                bank, self.batteries[i]
+            ))),
+            None => Ok(self.totals[i].to_string().into()),
+        }
+    }
+}
+
+impl Solution for BatteryBank {
+    const DAY: u8 = 3;
+
+    type Input = Joltages;
+
+    const FLAGS: &[&str] = &["k", "show"];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Self::parse_with(input, &mut Flags::default())
+    }
+
+    fn parse_with(input: &str, flags: &mut Flags) -> Result<Self::Input, Error> {
+        let mut joltages = Joltages::from_flags(flags)?;
+        for line in aoc_common::lines(input) {
+            joltages.add(line.parse()?);
+        }
+        Ok(joltages)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        input.total(1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        input.total(2)
+    }
+
+    fn details(input: &Self::Input, out: &mut dyn io::Write) -> io::Result<()> {
+        input.write_shown(out)
+    }
+}
+
+/// `--show` is refused, as it would keep every bank until the input ends.
+impl Streaming for BatteryBank {
+    type State = Joltages;
+
+    type Item = BatteryBank;
+
+    fn start(flags: &mut Flags) -> Result<Self::State, Error> {
+        let joltages = Joltages::from_flags(flags)?;
+        if joltages.show.is_some() {
+            return Err(Error::Usage(
+                "--show cannot be combined with --stream".to_owned(),
+            ));
+        }
+        Ok(joltages)
+    }
+
+    fn feed(state: &mut Self::State, line: Line<'_>) -> Result<Self::Item, ParseError> {
+        let bank = line.parse()?;
+        state.banks += 1;
+        Ok(bank)
+    }
+
+    fn fold(state: &mut Self::State, bank: &Self::Item, part: u8) {
+        state.select(bank, part);
+    }
+
+    fn answer(state: &Self::State, part: u8) -> Result<Answer, Error> {
+        state.total(part)
+    }
+}
+
+/// Writes `size` banks of a hundred batteries each.
+impl Generate for BatteryBank {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        for _ in 0..size {
+            let bank: String = (0..100)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect();
+            writeln!(out, "{}", bank)?;
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day3` binary.
+pub fn day() -> Day {
+    Day::streaming::<BatteryBank>().generating::<BatteryBank>()
+}
@@ -0,0 +1,87 @@
+//! Checks the single pass joltage against the window by window reference.
+
+use aoc_common::Rng;
+use day3::{BatteryBank, Highlight, JoltageSum};
+
+#[test]
+fn stack_matches_windows() {
+    let mut rng = Rng::new(3);
+    for _ in 0..2_000 {
+        let len = rng.range(1..=40) as usize;
+        // Few distinct joltages make ties, and runs of equal batteries, common.
+        let top = rng.range(1..=9) as u8;
+        let bank: String = (0..len)
+            .map(|_| char::from(b'0' + rng.range(1..=u64::from(top)) as u8))
+            .collect();
+        let bank: BatteryBank = bank.parse().unwrap();
+        for k in 1..=len.min(19) {
+            assert_eq!(
+                bank.max_joltage(k).unwrap().to_string(),
+                bank.max_joltage_windowed(k).unwrap().to_string(),
+                "k = {}",
+                k
+            );
+        }
+    }
+}
+
+#[test]
+fn long_banks_take_one_pass() {
+    let mut bank = "1".repeat(5_000_000);
+    bank.push_str("987654321987");
+    let bank: BatteryBank = bank.parse().unwrap();
+    assert_eq!(bank.max_joltage(12).unwrap().to_string(), "987654321987");
+}
+
+#[test]
+fn sums_are_exact_past_u64() {
+    let mut rng = Rng::new(24);
+    let mut sum = JoltageSum::default();
+    let mut expected: u128 = 0;
+    for _ in 0..1_000 {
+        let bank: String = (0..60)
+            .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+            .collect();
+        let joltage = bank
+            .parse::<BatteryBank>()
+            .unwrap()
+            .max_joltage(30)
+            .unwrap();
+        assert_eq!(joltage.digits().len(), 30);
+        expected += joltage.to_string().parse::<u128>().unwrap();
+        sum.add(&joltage);
+    }
+    assert_eq!(sum.to_string(), expected.to_string());
+}
+
+#[test]
+fn selections_name_the_batteries_of_the_joltage() {
+    let mut rng = Rng::new(25);
+    for _ in 0..500 {
+        let len = rng.range(1..=30) as usize;
+        let digits: Vec<u8> = (0..len).map(|_| rng.range(1..=9) as u8).collect();
+        let text: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+        let bank: BatteryBank = text.parse().unwrap();
+        let k = rng.range(1..=len as u64) as usize;
+        let selection = bank.select(k).unwrap();
+        assert!(selection.indices.windows(2).all(|pair| pair[0] < pair[1]));
+        let picked: Vec<u8> = selection.indices.iter().map(|&i| digits[i]).collect();
+        assert_eq!(picked, selection.joltage.digits(), "{} k = {}", text, k);
+    }
+
+    let bank: BatteryBank = "818181911112111".parse().unwrap();
+    let selection = bank.select(12).unwrap();
+    assert_eq!(
+        bank.highlight(&selection.indices, Highlight::Brackets),
+        "[8]1[8]1[8]1[911112111]"
+    );
+}
+
+#[test]
+fn short_banks_have_no_joltage() {
+    let bank: BatteryBank = "12345".parse().unwrap();
+    assert_eq!(bank.select(6), None);
+    assert_eq!(bank.max_joltage(6), None);
+    assert_eq!(bank.max_joltage_windowed(6), None);
+    assert_eq!(bank.max_joltage(5).unwrap().to_string(), "12345");
+}
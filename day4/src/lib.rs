@@ -0,0 +1,124 @@
+use std::{fmt, io, str::FromStr};
+
+use aoc_common::{Answer, Error, Generate, ParseError, Rng, Solution, grid::Pos, runner::Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    UnknownCell(char),
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::UnknownCell(c) => {
+                write!(f, "unknown cell `{}`, expected `@` or `.`", c)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// The printing department floor, marking where rolls of paper stand.
+#[derive(Clone)]
+pub struct Grid {
+    rolls: aoc_common::Grid<bool>,
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rolls = aoc_common::Grid::parse(s, |c| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(GridError::UnknownCell(c)),
+        })?;
+        Ok(Grid { rolls })
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.rolls.render(|&roll| if roll { '@' } else { '.' }))
+    }
+}
+
+impl Grid {
+    fn count_roll_neighbors(&self, pos: Pos) -> usize {
+        self.rolls
+            .neighbors8(pos)
+            .filter(|&neighbor| self.rolls[neighbor])
+            .count()
+    }
+
+    fn accessible_rolls(&self) -> impl Iterator<Item = Pos> {
+        self.rolls
+            .iter()
+            .filter(|&(_, &roll)| roll)
+            .map(|(pos, _)| pos)
+            .filter(|&pos| self.count_roll_neighbors(pos) < 4)
+    }
+
+    pub fn count_accessible_rolls(&self) -> usize {
+        self.accessible_rolls().count()
+    }
+
+    pub fn tick(&mut self) -> Option<usize> {
+        let to_remove: Vec<Pos> = self.accessible_rolls().collect();
+
+        if to_remove.is_empty() {
+            return None;
+        }
+
+        let count = to_remove.len();
+
+        for pos in to_remove {
+            self.rolls[pos] = false;
+        }
+
+        Some(count)
+    }
+}
+
+impl Solution for Grid {
+    const DAY: u8 = 4;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.count_accessible_rolls().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        let mut grid = input.clone();
+        let sum: usize = std::iter::from_fn(|| grid.tick()).sum();
+        Ok(sum.into())
+    }
+}
+
+/// Writes a `size` by `size` floor with rolls on about 60% of it.
+impl Generate for Grid {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        for _ in 0..size {
+            let row: String = (0..size)
+                .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                .collect();
+            writeln!(out, "{}", row)?;
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day4` binary.
+pub fn day() -> Day {
+    Day::of::<Grid>().generating::<Grid>()
+}
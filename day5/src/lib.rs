@@ -0,0 +1,153 @@
+use std::{fmt, num::ParseIntError, ops::RangeInclusive, str::FromStr};
+
+use aoc_common::{Answer, Error, ParseError, Solution, runner::Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DbError {
+    MissingHyphen,
+    InvalidId(ParseIntError),
+    /// No blank line separates the fresh ranges from the available ids.
+    MissingSeparator,
+}
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DbError::MissingHyphen => write!(f, "range must look like `START-STOP`"),
+            DbError::InvalidId(err) => write!(f, "invalid ingredient id: {}", err),
+            DbError::MissingSeparator => {
+                write!(
+                    f,
+                    "expected a blank line before the available ingredient ids"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DbError {}
+
+pub struct IngredientsDb {
+    ranges: Vec<RangeInclusive<u64>>,
+    available: Vec<u64>,
+}
+
+fn parse_id(id: &str, line: usize, column: usize) -> Result<u64, ParseError> {
+    id.parse()
+        .map_err(|err| ParseError::new(line, column, id, DbError::InvalidId(err)))
+}
+
+impl FromStr for IngredientsDb {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut valid_ranges = Vec::new();
+        let mut available = Vec::new();
+        let mut in_ranges = true;
+        let mut line_count = 0;
+
+        for (i, raw) in s.lines().enumerate() {
+            let line = i + 1;
+            let line_text = raw.trim();
+            let column = raw.len() - raw.trim_start().len() + 1;
+            line_count = line;
+
+            if line_text.is_empty() {
+                // Blank lines before the first range are not the separator.
+                if !valid_ranges.is_empty() {
+                    in_ranges = false;
+                }
+                continue;
+            }
+
+            if in_ranges {
+                let (start_str, end_str) = line_text.split_once('-').ok_or_else(|| {
+                    ParseError::new(line, column, line_text, DbError::MissingHyphen)
+                })?;
+                let start = parse_id(start_str, line, column)?;
+                let end = parse_id(end_str, line, column + start_str.len() + 1)?;
+                valid_ranges.push(start..=end);
+            } else {
+                available.push(parse_id(line_text, line, column)?);
+            }
+        }
+
+        if in_ranges {
+            return Err(ParseError::new(
+                line_count + 1,
+                1,
+                "",
+                DbError::MissingSeparator,
+            ));
+        }
+
+        let mut db = IngredientsDb {
+            ranges: valid_ranges,
+            available,
+        };
+        db.merge_ranges();
+        Ok(db)
+    }
+}
+
+impl IngredientsDb {
+    pub fn is_fresh(&self, id: u64) -> bool {
+        self.ranges.iter().any(|r| r.contains(&id))
+    }
+
+    pub fn total_fresh(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end() - r.start() + 1).sum()
+    }
+
+    fn merge_ranges(&mut self) {
+        if self.ranges.is_empty() {
+            return;
+        }
+
+        self.ranges.sort_by_key(|r| *r.start());
+
+        let mut merged = Vec::new();
+        let mut current_range = self.ranges[0].clone();
+
+        for next_range in self.ranges.iter().skip(1) {
+            if *next_range.start() <= *current_range.end() + 1 {
+                let new_end = std::cmp::max(*current_range.end(), *next_range.end());
+                current_range = *current_range.start()..=new_end;
+            } else {
+                merged.push(current_range);
+                current_range = next_range.clone();
+            }
+        }
+        merged.push(current_range);
+
+        self.ranges = merged;
+    }
+}
+
+impl Solution for IngredientsDb {
+    const DAY: u8 = 5;
+
+    type Input = IngredientsDb;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        let count = input
+            .available
+            .iter()
+            .filter(|&&id| input.is_fresh(id))
+            .count();
+        Ok(count.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.total_fresh().into())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day5` binary.
+pub fn day() -> Day {
+    Day::of::<IngredientsDb>()
+}
@@ -0,0 +1,205 @@
+use std::{fmt, str::FromStr};
+
+use aoc_common::{Answer, Error, ParseError, Solution, runner::Day};
+
+enum Operator {
+    Add,
+    Multiply,
+}
+
+/// How the digits of a problem are read into numbers.
+#[derive(Clone, Copy)]
+pub enum Reading {
+    /// Each row holds one number, the way humans write arithmetic.
+    Rows,
+    /// Each column holds one number, most significant digit at the top, read
+    /// right to left the way cephalopods write arithmetic.
+    Columns,
+}
+pub struct Worksheet {
+    grid: Vec<Vec<u8>>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorksheetError {
+    UnexpectedChar(char),
+}
+
+impl fmt::Display for WorksheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorksheetError::UnexpectedChar(c) => {
+                write!(f, "unexpected `{}`, expected digits, spaces, `+` or `*`", c)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorksheetError {}
+
+impl FromStr for Worksheet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for (i, line) in s.lines().enumerate() {
+            if let Some((column, c)) = line
+                .char_indices()
+                .find(|&(_, c)| !matches!(c, '0'..='9' | ' ' | '+' | '*'))
+            {
+                return Err(ParseError::new(
+                    i + 1,
+                    column + 1,
+                    c,
+                    WorksheetError::UnexpectedChar(c),
+                ));
+            }
+        }
+
+        let lines: Vec<&str> = s.lines().filter(|l| !l.is_empty()).collect();
+        let height = lines.len();
+        let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+        let grid = lines
+            .into_iter()
+            .map(|line| {
+                let mut bytes = line.as_bytes().to_vec();
+                bytes.resize(width, b' ');
+                bytes
+            })
+            .collect();
+
+        Ok(Worksheet {
+            grid,
+            width,
+            height,
+        })
+    }
+}
+
+impl Worksheet {
+    pub fn solver(&self, reading: Reading) -> Solver<'_> {
+        Solver {
+            worksheet: self,
+            reading,
+            current_col: 0,
+        }
+    }
+}
+pub struct Solver<'a> {
+    worksheet: &'a Worksheet,
+    reading: Reading,
+    current_col: usize,
+}
+
+impl<'a> Iterator for Solver<'a> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_col >= self.worksheet.width {
+            return None;
+        }
+
+        let mut start = self.current_col;
+
+        while start < self.worksheet.width {
+            let is_sep =
+                (0..self.worksheet.height).all(|row| self.worksheet.grid[row][start] == b' ');
+            if !is_sep {
+                break;
+            }
+            start += 1;
+        }
+
+        if start >= self.worksheet.width {
+            self.current_col = start;
+            return None;
+        }
+
+        let mut end = start;
+        while end < self.worksheet.width {
+            let is_sep = (0..self.worksheet.height).all(|r| self.worksheet.grid[r][end] == b' ');
+            if is_sep {
+                break;
+            }
+            end += 1;
+        }
+
+        self.current_col = end + 1;
+        let mut numbers = Vec::new();
+        let mut operation = Operator::Add; // Default
+        for c in start..end {
+            for r in 0..self.worksheet.height {
+                match self.worksheet.grid[r][c] {
+                    b'+' => operation = Operator::Add,
+                    b'*' => operation = Operator::Multiply,
+                    _ => {}
+                }
+            }
+        }
+
+        match self.reading {
+            Reading::Rows => {
+                for row in &self.worksheet.grid {
+                    let digit_str: String = row[start..end]
+                        .iter()
+                        .filter(|byte| byte.is_ascii_digit())
+                        .map(|&byte| byte as char)
+                        .collect();
+
+                    if let Ok(num) = digit_str.parse::<u64>() {
+                        numbers.push(num);
+                    }
+                }
+            }
+            Reading::Columns => {
+                for c in (start..end).rev() {
+                    let mut digit_str = String::new();
+
+                    for r in 0..self.worksheet.height {
+                        let byte = self.worksheet.grid[r][c];
+                        if byte.is_ascii_digit() {
+                            digit_str.push(byte as char);
+                        }
+                    }
+
+                    if let Ok(num) = digit_str.parse::<u64>() {
+                        numbers.push(num);
+                    }
+                }
+            }
+        }
+
+        let res = match operation {
+            Operator::Add => numbers.iter().sum(),
+            Operator::Multiply => numbers.iter().product(),
+        };
+        Some(res)
+    }
+}
+
+impl Solution for Worksheet {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        let sum: u64 = input.solver(Reading::Rows).sum();
+        Ok(sum.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        let sum: u64 = input.solver(Reading::Columns).sum();
+        Ok(sum.into())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day6` binary.
+pub fn day() -> Day {
+    Day::of::<Worksheet>()
+}
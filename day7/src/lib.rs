@@ -0,0 +1,141 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use aoc_common::{Answer, Error, Grid, ParseError, Solution, grid::Pos, runner::Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifoldError {
+    UnknownCell(char),
+    MissingStart,
+}
+
+impl fmt::Display for ManifoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifoldError::UnknownCell(c) => {
+                write!(f, "unknown cell `{}`, expected `.`, `^` or `S`", c)
+            }
+            ManifoldError::MissingStart => write!(f, "no `S` marks where the beam enters"),
+        }
+    }
+}
+
+impl std::error::Error for ManifoldError {}
+
+pub struct TachyonManifold {
+    grid: Grid<char>,
+    start: Pos,
+}
+
+impl FromStr for TachyonManifold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, |c| match c {
+            '.' | '^' | 'S' => Ok(c),
+            _ => Err(ManifoldError::UnknownCell(c)),
+        })?;
+
+        let start = grid
+            .position(|&c| c == 'S')
+            .ok_or_else(|| ParseError::new(1, 1, "", ManifoldError::MissingStart))?;
+
+        Ok(TachyonManifold { grid, start })
+    }
+}
+
+impl fmt::Display for TachyonManifold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt(f)
+    }
+}
+
+impl TachyonManifold {
+    /// The columns a beam moves to after hitting the splitter at `pos`; a
+    /// `None` beam leaves the side of the manifold.
+    fn split(&self, pos: Pos) -> [Option<usize>; 2] {
+        [(-1, 0), (1, 0)].map(|offset| self.grid.step(pos, offset).map(|(x, _)| x))
+    }
+
+    /// Counts how many times a beam hits a splitter. Beams that meet merge
+    /// into one, so each splitter splits at most once.
+    pub fn count_splits(&self) -> u64 {
+        let mut beams: HashSet<usize> = HashSet::new();
+        beams.insert(self.start.0);
+
+        let mut splits = 0;
+
+        for y in self.start.1..self.grid.height() {
+            let mut next_beams = HashSet::new();
+
+            for &x in &beams {
+                if self.grid[(x, y)] == '^' {
+                    splits += 1;
+                    next_beams.extend(self.split((x, y)).into_iter().flatten());
+                } else {
+                    next_beams.insert(x);
+                }
+            }
+            beams = next_beams;
+        }
+
+        splits
+    }
+
+    pub fn simulate(&self) -> u64 {
+        let mut active_paths: HashMap<usize, u64> = HashMap::new();
+        active_paths.insert(self.start.0, 1);
+
+        let mut completed_timelines = 0;
+
+        for y in self.start.1..self.grid.height() {
+            let mut next_paths: HashMap<usize, u64> = HashMap::new();
+
+            for (&x, &count) in &active_paths {
+                match self.grid[(x, y)] {
+                    '^' => {
+                        for beam in self.split((x, y)) {
+                            match beam {
+                                Some(x) => *next_paths.entry(x).or_insert(0) += count,
+                                None => completed_timelines += count,
+                            }
+                        }
+                    }
+                    _ => {
+                        *next_paths.entry(x).or_insert(0) += count;
+                    }
+                }
+            }
+            active_paths = next_paths;
+        }
+        let bottom_exits: u64 = active_paths.values().sum();
+
+        completed_timelines + bottom_exits
+    }
+}
+
+impl Solution for TachyonManifold {
+    const DAY: u8 = 7;
+
+    type Input = TachyonManifold;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.count_splits().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.simulate().into())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day7` binary.
+pub fn day() -> Day {
+    Day::of::<TachyonManifold>()
+}
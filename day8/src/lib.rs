@@ -0,0 +1,230 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use aoc_common::{
+    Answer, Error, Line, ParseError, Solution, Streaming,
+    runner::{Day, Flags},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointError {
+    WrongArity(usize),
+    InvalidCoordinate(ParseIntError),
+}
+
+impl fmt::Display for PointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointError::WrongArity(n) => write!(f, "expected 3 coordinates, found {}", n),
+            PointError::InvalidCoordinate(err) => write!(f, "invalid coordinate: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PointError {}
+
+pub struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut v = Vec::with_capacity(3);
+        let mut column = 1;
+        for field in s.split(',') {
+            let coordinate = field
+                .parse()
+                .map_err(|err| ParseError::at(column, field, PointError::InvalidCoordinate(err)))?;
+            v.push(coordinate);
+            column += field.len() + 1;
+        }
+        if v.len() != 3 {
+            return Err(ParseError::at(1, s, PointError::WrongArity(v.len())));
+        }
+
+        Ok(Point {
+            x: v[0],
+            y: v[1],
+            z: v[2],
+        })
+    }
+}
+
+impl Point {
+    fn dist_sq(&self, other: &Point) -> i64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+struct Edge {
+    u: usize,
+    v: usize,
+    dist_sq: i64,
+}
+
+struct UnionFind {
+    parent: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            count: n,
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        if self.parent[i] != i {
+            self.parent[i] = self.find(self.parent[i]);
+        }
+        self.parent[i]
+    }
+
+    fn union(&mut self, i: usize, j: usize) -> bool {
+        let root_i = self.find(i);
+        let root_j = self.find(j);
+
+        if root_i != root_j {
+            self.parent[root_i] = root_j;
+            self.count -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The junction boxes hanging in the playground.
+pub struct Playground {
+    points: Vec<Point>,
+    /// How many of the closest pairs part 1 connects.
+    connections: usize,
+}
+
+fn closest_pairs(points: &[Point]) -> Vec<Edge> {
+    let n = points.len();
+    let mut edges = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            edges.push(Edge {
+                u: i,
+                v: j,
+                dist_sq: points[i].dist_sq(&points[j]),
+            });
+        }
+    }
+
+    edges.sort_by_key(|e| e.dist_sq);
+    edges
+}
+
+/// Connects the `connections` closest pairs and multiplies together the sizes
+/// of the three largest circuits that result.
+pub fn largest_circuits(points: &[Point], connections: usize) -> usize {
+    let edges = closest_pairs(points);
+    let n = points.len();
+
+    let mut dsu = UnionFind::new(n);
+    let limit = connections.min(edges.len());
+
+    for edge in &edges[..limit] {
+        dsu.union(edge.u, edge.v);
+    }
+
+    let mut sizes = vec![0; n];
+    for i in 0..n {
+        sizes[dsu.find(i)] += 1;
+    }
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    sizes.iter().take(3).product()
+}
+
+/// Keeps connecting the closest pairs until everything is one circuit and
+/// multiplies the X coordinates of the last two boxes joined.
+pub fn solve(points: &[Point]) -> i64 {
+    let edges = closest_pairs(points);
+    let mut dsu = UnionFind::new(points.len());
+
+    for edge in edges {
+        if dsu.union(edge.u, edge.v) && dsu.count == 1 {
+            let p1 = &points[edge.u];
+            let p2 = &points[edge.v];
+            return p1.x * p2.x;
+        }
+    }
+
+    0
+}
+
+/// Connections part 1 makes unless `--connections=N` says otherwise.
+const CONNECTIONS: usize = 1000;
+
+impl Solution for Playground {
+    const DAY: u8 = 8;
+
+    type Input = Playground;
+
+    const FLAGS: &[&str] = &["connections"];
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Playground {
+            points: aoc_common::parse_lines(input)?,
+            connections: CONNECTIONS,
+        })
+    }
+
+    fn parse_with(input: &str, flags: &mut Flags) -> Result<Self::Input, Error> {
+        let mut playground = Self::parse(input)?;
+        if let Some(connections) = flags.value("connections")? {
+            playground.connections = connections;
+        }
+        Ok(playground)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(largest_circuits(&input.points, input.connections).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(solve(&input.points).into())
+    }
+}
+
+/// Every pair of boxes is compared, so streaming only saves holding the text.
+impl Streaming for Playground {
+    type State = Playground;
+
+    type Item = ();
+
+    fn start(flags: &mut Flags) -> Result<Self::State, Error> {
+        Ok(Playground {
+            points: Vec::new(),
+            connections: flags.value("connections")?.unwrap_or(CONNECTIONS),
+        })
+    }
+
+    fn feed(state: &mut Self::State, line: Line<'_>) -> Result<(), ParseError> {
+        state.points.push(line.parse()?);
+        Ok(())
+    }
+
+    fn answer(state: &Self::State, part: u8) -> Result<Answer, Error> {
+        aoc_common::runner::solve::<Self>(state, part)
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day8` binary.
+pub fn day() -> Day {
+    Day::streaming::<Playground>()
+}
@@ -0,0 +1,254 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use aoc_common::{Answer, Error, Generate, ParseError, Rng, Solution, runner::Day};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointError {
+    MissingComma,
+    InvalidCoordinate(ParseIntError),
+}
+
+impl fmt::Display for PointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointError::MissingComma => write!(f, "point must look like `X,Y`"),
+            PointError::InvalidCoordinate(err) => write!(f, "invalid coordinate: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PointError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+fn parse_coordinate(field: &str, column: usize) -> Result<i64, ParseError> {
+    let token = field.trim();
+    let column = column + field.len() - field.trim_start().len();
+    token
+        .parse()
+        .map_err(|err| ParseError::at(column, token, PointError::InvalidCoordinate(err)))
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x_s, y_s) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(1, s, PointError::MissingComma))?;
+        Ok(Point {
+            x: parse_coordinate(x_s, 1)?,
+            y: parse_coordinate(y_s, x_s.len() + 2)?,
+        })
+    }
+}
+
+struct Edge {
+    p1: Point,
+    p2: Point,
+}
+
+impl Edge {
+    fn intersects_box_interior(&self, min_x: i64, max_x: i64, min_y: i64, max_y: i64) -> bool {
+        let ex_min = min(self.p1.x, self.p2.x);
+        let ex_max = max(self.p1.x, self.p2.x);
+        let ey_min = min(self.p1.y, self.p2.y);
+        let ey_max = max(self.p1.y, self.p2.y);
+
+        let x_overlap_start = max(min_x, ex_min);
+        let x_overlap_end = min(max_x, ex_max);
+
+        let has_x_overlap = if ex_min == ex_max {
+            ex_min > min_x && ex_min < max_x
+        } else {
+            x_overlap_start < x_overlap_end
+        };
+
+        let y_overlap_start = max(min_y, ey_min);
+        let y_overlap_end = min(max_y, ey_max);
+
+        let has_y_overlap = if ey_min == ey_max {
+            ey_min > min_y && ey_min < max_y
+        } else {
+            y_overlap_start < y_overlap_end
+        };
+
+        has_x_overlap && has_y_overlap
+    }
+}
+
+pub struct Polygon {
+    vertices: Vec<Point>,
+    edges: Vec<Edge>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        let mut edges = Vec::new();
+        if !vertices.is_empty() {
+            for i in 0..vertices.len() {
+                let p1 = vertices[i];
+                let p2 = vertices[(i + 1) % vertices.len()];
+                edges.push(Edge { p1, p2 });
+            }
+        }
+        Polygon { vertices, edges }
+    }
+
+    fn contains_point(&self, x: f64, y: f64) -> bool {
+        let mut inside = false;
+        for edge in &self.edges {
+            let p1 = edge.p1;
+            let p2 = edge.p2;
+
+            let y1 = p1.y as f64;
+            let y2 = p2.y as f64;
+
+            let min_y = y1.min(y2);
+            let max_y = y1.max(y2);
+
+            if y > min_y && y <= max_y {
+                let x1 = p1.x as f64;
+                let x2 = p2.x as f64;
+
+                let x_intersect = if x1 == x2 {
+                    x1
+                } else {
+                    x1 + (y - y1) / (y2 - y1) * (x2 - x1)
+                };
+
+                if x < x_intersect {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Largest rectangle with two vertices as opposite corners, ignoring
+    /// whether it stays inside the polygon.
+    pub fn largest_corner_rect(&self) -> i64 {
+        let mut max_area = 0;
+
+        for (i, &p1) in self.vertices.iter().enumerate() {
+            for &p2 in self.vertices.iter().skip(i + 1) {
+                let width = (p1.x - p2.x).abs() + 1;
+                let height = (p1.y - p2.y).abs() + 1;
+                max_area = max(max_area, width * height);
+            }
+        }
+        max_area
+    }
+
+    pub fn solve_largest_rect(&self) -> i64 {
+        let mut max_area = 0;
+
+        for (i, &p1) in self.vertices.iter().enumerate() {
+            for &p2 in self.vertices.iter().skip(i + 1) {
+                let min_x = min(p1.x, p2.x);
+                let max_x = max(p1.x, p2.x);
+                let min_y = min(p1.y, p2.y);
+                let max_y = max(p1.y, p2.y);
+
+                let width = max_x - min_x + 1;
+                let height = max_y - min_y + 1;
+                let area = width * height;
+                if area <= max_area {
+                    continue;
+                }
+
+                let has_intersection = self
+                    .edges
+                    .iter()
+                    .any(|e| e.intersects_box_interior(min_x, max_x, min_y, max_y));
+
+                if has_intersection {
+                    continue;
+                }
+
+                let center_x = (min_x as f64 + max_x as f64) / 2.0;
+                let center_y = (min_y as f64 + max_y as f64) / 2.0;
+
+                if self.contains_point(center_x, center_y) {
+                    max_area = area;
+                }
+            }
+        }
+        max_area
+    }
+}
+
+impl Solution for Polygon {
+    const DAY: u8 = 9;
+
+    type Input = Polygon;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(Polygon::new(aoc_common::parse_lines(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.largest_corner_rect().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error> {
+        Ok(input.solve_largest_rect().into())
+    }
+}
+
+/// Writes a polygon of about `size` corners: bars side by side, each running
+/// from a random bottom below the middle to a random top above it, so that
+/// neighbouring bars always overlap.
+impl Generate for Polygon {
+    fn generate(
+        rng: &mut Rng,
+        size: usize,
+        out: &mut dyn io::Write,
+    ) -> io::Result<Vec<(u8, Answer)>> {
+        let bars = (size / 4).max(1);
+        let mut xs = vec![rng.range(0..=1000)];
+        let mut tops: Vec<u64> = Vec::with_capacity(bars);
+        let mut bottoms: Vec<u64> = Vec::with_capacity(bars);
+        for _ in 0..bars {
+            xs.push(xs[xs.len() - 1] + rng.range(1..=2000));
+            // Neighbours at the same height would put three corners in a line.
+            let top = loop {
+                let top = rng.range(50_000..=99_999);
+                if tops.last() != Some(&top) {
+                    break top;
+                }
+            };
+            let bottom = loop {
+                let bottom = rng.range(0..=49_999);
+                if bottoms.last() != Some(&bottom) {
+                    break bottom;
+                }
+            };
+            tops.push(top);
+            bottoms.push(bottom);
+        }
+
+        for i in 0..bars {
+            writeln!(out, "{},{}", xs[i], tops[i])?;
+            writeln!(out, "{},{}", xs[i + 1], tops[i])?;
+        }
+        for i in (0..bars).rev() {
+            writeln!(out, "{},{}", xs[i + 1], bottoms[i])?;
+            writeln!(out, "{},{}", xs[i], bottoms[i])?;
+        }
+        Ok(Vec::new())
+    }
+}
+
+/// This day's entry points for the `aoc` runner and the `day9` binary.
+pub fn day() -> Day {
+    Day::of::<Polygon>().generating::<Polygon>()
+}